@@ -0,0 +1,133 @@
+//! Compare stepping the async traits with a boxed-future baseline, as generated by
+//! `#[async_trait]`, which the async traits used previously.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use either::Either::Left;
+use futures::executor::block_on;
+use moveiter::{AsyncFiniteMoveIterator, AsyncTerminalMoveIterator, FiniteMoveIterator};
+use std::future::Future;
+use std::pin::Pin;
+
+const LEN: u64 = 10_000;
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// The previous shape of [AsyncFiniteMoveIterator::into_next], with one allocation per step.
+trait BoxedAsyncFiniteMoveIterator: Sized + Send {
+    type Item;
+
+    fn into_next(self) -> BoxFuture<Option<(Self, Self::Item)>>;
+}
+
+struct BoxedRange(std::ops::Range<u64>);
+
+impl BoxedAsyncFiniteMoveIterator for BoxedRange {
+    type Item = u64;
+
+    fn into_next(self) -> BoxFuture<Option<(Self, Self::Item)>> {
+        Box::pin(async move {
+            let BoxedRange(mut range) = self;
+            let x = range.next()?;
+            Some((BoxedRange(range), x))
+        })
+    }
+}
+
+/// A baseline adapter which boxes its own step around the boxed inner step, as `#[async_trait]`
+/// adapters did.
+struct BoxedAsTerminal<I>(I);
+
+impl<I> BoxedAsTerminal<I>
+where
+    I: BoxedAsyncFiniteMoveIterator + 'static,
+{
+    fn into_next(self) -> BoxFuture<Result<(Self, I::Item), ()>> {
+        Box::pin(async move {
+            match self.0.into_next().await {
+                Some((next, x)) => Ok((BoxedAsTerminal(next), x)),
+                None => Err(()),
+            }
+        })
+    }
+}
+
+async fn sum_boxed(mut it: BoxedRange) -> u64 {
+    let mut sum = 0;
+    while let Some((next, x)) = it.into_next().await {
+        it = next;
+        sum += x;
+    }
+    sum
+}
+
+async fn sum_boxed_adapted(mut it: BoxedAsTerminal<BoxedRange>) -> u64 {
+    let mut sum = 0;
+    while let Ok((next, x)) = it.into_next().await {
+        it = next;
+        sum += x;
+    }
+    sum
+}
+
+// The native sides use the same loop shape as the boxed baselines, so only the step differs.
+async fn sum_native<I>(mut it: I) -> u64
+where
+    I: AsyncFiniteMoveIterator<Item = u64>,
+{
+    let mut sum = 0;
+    while let Some((next, x)) = it.into_next().await {
+        it = next;
+        sum += x;
+    }
+    sum
+}
+
+async fn sum_native_adapted<I>(mut it: I) -> u64
+where
+    I: AsyncTerminalMoveIterator<Item = u64, Terminal = ()>,
+{
+    let mut sum = 0;
+    while let Left((next, x)) = it.into_next().await {
+        it = next;
+        sum += x;
+    }
+    sum
+}
+
+fn bench_step(c: &mut Criterion) {
+    let mut group = c.benchmark_group("finite_sum");
+
+    group.bench_function("boxed", |b| {
+        b.iter(|| block_on(sum_boxed(BoxedRange(0..black_box(LEN)))))
+    });
+    group.bench_function("native", |b| {
+        b.iter(|| {
+            let it = FiniteMoveIterator::into_async(0..black_box(LEN));
+            block_on(sum_native(it))
+        })
+    });
+
+    group.finish();
+
+    let mut group = c.benchmark_group("finite_as_terminal_sum");
+
+    group.bench_function("boxed", |b| {
+        b.iter(|| {
+            block_on(sum_boxed_adapted(BoxedAsTerminal(BoxedRange(
+                0..black_box(LEN),
+            ))))
+        })
+    });
+    group.bench_function("native", |b| {
+        b.iter(|| {
+            let it = FiniteMoveIterator::into_async(0..black_box(LEN))
+                .into_async_terminal_move_iterator();
+            block_on(sum_native_adapted(it))
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_step);
+criterion_main!(benches);
@@ -0,0 +1,340 @@
+//! Derive macros for the `moveiter` traits on state-machine enums.
+//!
+//! Each variant of the enum is a state, annotated with a transition function which receives the
+//! variant's fields by value and produces the result of one `into_next` step. The enum itself
+//! declares the `Item`, and for terminal iterators the `Terminal`:
+//!
+//! ```
+//! use either::Either::{self, Left, Right};
+//! use moveiter::TerminalMoveIterator;
+//!
+//! #[derive(TerminalMoveIterator)]
+//! #[moveiter(item = u8, terminal = Result<(), String>)]
+//! enum Packet {
+//!     #[moveiter(transition = read_header)]
+//!     Header(Vec<u8>),
+//!     #[moveiter(transition = read_body)]
+//!     Body { remaining: usize, bytes: Vec<u8> },
+//! }
+//!
+//! fn read_header(mut bytes: Vec<u8>) -> Either<(Packet, u8), Result<(), String>> {
+//!     if bytes.is_empty() {
+//!         return Right(Err("missing header".to_string()));
+//!     }
+//!     let len = bytes.remove(0);
+//!     Left((Packet::Body { remaining: len as usize, bytes }, len))
+//! }
+//!
+//! fn read_body(remaining: usize, mut bytes: Vec<u8>) -> Either<(Packet, u8), Result<(), String>> {
+//!     match (remaining, bytes.is_empty()) {
+//!         (0, _) => Right(Ok(())),
+//!         (_, true) => Right(Err("truncated body".to_string())),
+//!         (_, false) => {
+//!             let x = bytes.remove(0);
+//!             Left((Packet::Body { remaining: remaining - 1, bytes }, x))
+//!         }
+//!     }
+//! }
+//!
+//! let mut items = vec![];
+//! let term = Packet::Header(vec![2, 7, 9]).for_each(|x| items.push(x));
+//!
+//! assert_eq!(items, vec![2, 7, 9]);
+//! assert_eq!(term, Ok(()));
+//! ```
+//!
+//! The `Async*` derives instead call an `async` transition function and await it:
+//!
+//! ```
+//! # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//! use moveiter::{AsyncFiniteMoveIterator, AsyncFiniteMoveIteratorExt};
+//!
+//! #[derive(AsyncFiniteMoveIterator)]
+//! #[moveiter(item = u32)]
+//! enum Countdown {
+//!     #[moveiter(transition = tick)]
+//!     Running(u32),
+//!     #[moveiter(transition = stopped)]
+//!     Stopped,
+//! }
+//!
+//! async fn tick(n: u32) -> Option<(Countdown, u32)> {
+//!     let next = if n == 0 { Countdown::Stopped } else { Countdown::Running(n - 1) };
+//!     Some((next, n))
+//! }
+//!
+//! async fn stopped() -> Option<(Countdown, u32)> {
+//!     None
+//! }
+//!
+//! let items: Vec<u32> = Countdown::Running(2).collect().await;
+//! assert_eq!(items, vec![2, 1, 0]);
+//! # });
+//! ```
+//!
+//! # Usage
+//!
+//! These derives are re-exported from `moveiter` with its `derive` feature, which is the only
+//! supported way to use them. The generated code names `::moveiter::__private`, which is not a
+//! stable API, so depend on `moveiter` with `features = ["derive"]` rather than on this crate
+//! directly.
+//!
+//! # Errors
+//!
+//! Every derive requires the `item` on the enum:
+//!
+//! ```compile_fail
+//! #[derive(moveiter::FiniteMoveIterator)]
+//! enum Missing {
+//!     #[moveiter(transition = stop)]
+//!     Stopped,
+//! }
+//!
+//! fn stop() -> Option<(Missing, u8)> {
+//!     None
+//! }
+//! ```
+//!
+//! The terminal derives also require the `terminal`:
+//!
+//! ```compile_fail
+//! #[derive(moveiter::TerminalMoveIterator)]
+//! #[moveiter(item = u8)]
+//! enum Missing {
+//!     #[moveiter(transition = stop)]
+//!     Stopped,
+//! }
+//!
+//! fn stop() -> either::Either<(Missing, u8), ()> {
+//!     either::Either::Right(())
+//! }
+//! ```
+//!
+//! Every variant requires a `transition`:
+//!
+//! ```compile_fail
+//! #[derive(moveiter::FiniteMoveIterator)]
+//! #[moveiter(item = u8)]
+//! enum Missing {
+//!     Stopped,
+//! }
+//! ```
+//!
+//! Only enums are supported:
+//!
+//! ```compile_fail
+//! #[derive(moveiter::FiniteMoveIterator)]
+//! #[moveiter(item = u8)]
+//! struct NotAnEnum;
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Ident, Path, Type};
+
+/// Derive `moveiter::EndlessMoveIterator` for an enum, with `#[moveiter(item = ..)]`.
+#[proc_macro_derive(EndlessMoveIterator, attributes(moveiter))]
+pub fn derive_endless(input: TokenStream) -> TokenStream {
+    expand(input, Family::Endless, false)
+}
+
+/// Derive `moveiter::FiniteMoveIterator` for an enum, with `#[moveiter(item = ..)]`.
+#[proc_macro_derive(FiniteMoveIterator, attributes(moveiter))]
+pub fn derive_finite(input: TokenStream) -> TokenStream {
+    expand(input, Family::Finite, false)
+}
+
+/// Derive `moveiter::TerminalMoveIterator` for an enum, with
+/// `#[moveiter(item = .., terminal = ..)]`.
+#[proc_macro_derive(TerminalMoveIterator, attributes(moveiter))]
+pub fn derive_terminal(input: TokenStream) -> TokenStream {
+    expand(input, Family::Terminal, false)
+}
+
+/// Derive `moveiter::AsyncEndlessMoveIterator` for an enum, with `#[moveiter(item = ..)]`.
+#[proc_macro_derive(AsyncEndlessMoveIterator, attributes(moveiter))]
+pub fn derive_async_endless(input: TokenStream) -> TokenStream {
+    expand(input, Family::Endless, true)
+}
+
+/// Derive `moveiter::AsyncFiniteMoveIterator` for an enum, with `#[moveiter(item = ..)]`.
+#[proc_macro_derive(AsyncFiniteMoveIterator, attributes(moveiter))]
+pub fn derive_async_finite(input: TokenStream) -> TokenStream {
+    expand(input, Family::Finite, true)
+}
+
+/// Derive `moveiter::AsyncTerminalMoveIterator` for an enum, with
+/// `#[moveiter(item = .., terminal = ..)]`.
+#[proc_macro_derive(AsyncTerminalMoveIterator, attributes(moveiter))]
+pub fn derive_async_terminal(input: TokenStream) -> TokenStream {
+    expand(input, Family::Terminal, true)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Family {
+    Endless,
+    Finite,
+    Terminal,
+}
+
+// The types declared by the `#[moveiter(..)]` attribute on the enum.
+struct Config {
+    item: Type,
+    terminal: Option<Type>,
+}
+
+fn expand(input: TokenStream, family: Family, asyncness: bool) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_derive(&input, family, asyncness)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_derive(
+    input: &DeriveInput,
+    family: Family,
+    asyncness: bool,
+) -> syn::Result<TokenStream2> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "moveiter derives only support enums",
+            ))
+        }
+    };
+
+    let config = parse_config(&input.ident, &input.attrs, family)?;
+
+    let mut arms = vec![];
+    for variant in &data.variants {
+        let transition = parse_transition(&variant.attrs)?.ok_or_else(|| {
+            syn::Error::new_spanned(
+                &variant.ident,
+                "missing `#[moveiter(transition = path)]` on variant",
+            )
+        })?;
+
+        let vident = &variant.ident;
+        let (pattern, args) = match &variant.fields {
+            Fields::Unit => (quote! { Self::#vident }, vec![]),
+            Fields::Unnamed(fields) => {
+                let binds: Vec<_> = (0..fields.unnamed.len())
+                    .map(|i| format_ident!("__field{}", i))
+                    .collect();
+                (quote! { Self::#vident(#(#binds),*) }, binds)
+            }
+            Fields::Named(fields) => {
+                let binds: Vec<_> = fields
+                    .named
+                    .iter()
+                    .map(|f| f.ident.clone().unwrap())
+                    .collect();
+                (quote! { Self::#vident { #(#binds),* } }, binds)
+            }
+        };
+
+        let call = quote! { #transition(#(#args),*) };
+        let call = if asyncness {
+            quote! { #call.await }
+        } else {
+            call
+        };
+        arms.push(quote! { #pattern => #call, });
+    }
+
+    let traitname = format_ident!(
+        "{}{}MoveIterator",
+        if asyncness { "Async" } else { "" },
+        match family {
+            Family::Endless => "Endless",
+            Family::Finite => "Finite",
+            Family::Terminal => "Terminal",
+        }
+    );
+
+    let item = &config.item;
+    let terminal = config
+        .terminal
+        .as_ref()
+        .map(|t| quote! { type Terminal = #t; });
+    let output = match family {
+        Family::Endless => quote! { (Self, Self::Item) },
+        Family::Finite => quote! { ::core::option::Option<(Self, Self::Item)> },
+        Family::Terminal => {
+            quote! { ::moveiter::__private::Either<(Self, Self::Item), Self::Terminal> }
+        }
+    };
+    let asynckw = if asyncness {
+        Some(quote! { async })
+    } else {
+        None
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::moveiter::#traitname for #ident #ty_generics #where_clause {
+            type Item = #item;
+            #terminal
+
+            #asynckw fn into_next(self) -> #output {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+fn parse_config(ident: &Ident, attrs: &[Attribute], family: Family) -> syn::Result<Config> {
+    let mut item = None;
+    let mut terminal = None;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("moveiter")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("item") {
+                item = Some(meta.value()?.parse::<Type>()?);
+                Ok(())
+            } else if meta.path.is_ident("terminal") && family == Family::Terminal {
+                terminal = Some(meta.value()?.parse::<Type>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported moveiter attribute"))
+            }
+        })?;
+    }
+
+    let item = item.ok_or_else(|| {
+        syn::Error::new_spanned(ident, "missing `#[moveiter(item = Type)]` on enum")
+    })?;
+
+    if family == Family::Terminal && terminal.is_none() {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "missing `#[moveiter(terminal = Type)]` on enum",
+        ));
+    }
+
+    Ok(Config { item, terminal })
+}
+
+fn parse_transition(attrs: &[Attribute]) -> syn::Result<Option<Path>> {
+    let mut transition = None;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("moveiter")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("transition") {
+                transition = Some(meta.value()?.parse::<Path>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported moveiter attribute"))
+            }
+        })?;
+    }
+
+    Ok(transition)
+}
@@ -3,26 +3,320 @@
 //! These are constructed from traits methods such as
 //! [AsyncFiniteMoveIterator::into_async_terminal_move_iterator](crate::AsyncFiniteMoveIterator::into_async_terminal_move_iterator).
 
+mod aemi_as_blocking;
 mod aemi_as_finite;
+mod aemi_as_local;
 mod aemi_as_terminal;
+mod aemi_filter_async;
+mod aemi_filter_map_async;
+mod aemi_repeat_with;
+#[cfg(feature = "futures")]
+mod aemi_stream;
+mod aemi_then;
+mod aemi_unfold;
+mod afmi_as_blocking;
+mod afmi_as_endless_or;
+mod afmi_as_local;
 mod afmi_as_terminal;
+mod afmi_buffer_unordered;
+mod afmi_buffered;
+mod afmi_chain;
+mod afmi_filter_async;
+mod afmi_filter_map_async;
+mod afmi_peekable;
+mod afmi_scan;
+#[cfg(feature = "futures")]
+mod afmi_stream;
+mod afmi_then;
+mod afmi_unfold;
+mod afmi_zip;
+mod afmi_zip_longest;
+mod atmi_and_then_terminal;
+mod atmi_as_blocking;
+mod atmi_as_endless_or;
+mod atmi_as_finite_discarding;
+mod atmi_as_finite_with_slot;
+mod atmi_as_local;
+mod atmi_buffer_unordered;
+mod atmi_buffered;
+mod atmi_continue_with;
+mod atmi_filter_async;
+mod atmi_filter_map_async;
+mod atmi_map_err;
+mod atmi_map_terminal;
+mod atmi_peekable;
+mod atmi_scan;
+#[cfg(feature = "futures")]
+mod atmi_stream;
+mod atmi_then;
+#[cfg(feature = "futures")]
+mod atmi_try_stream;
+mod atmi_try_then;
+mod atmi_unfold;
+#[cfg(feature = "tokio")]
+mod atmi_with_timeout;
+mod atmi_zip;
+mod atmi_zip_longest;
+mod either_or_both;
 mod emi_as_async;
 mod emi_as_finite;
 mod emi_as_iterator;
 mod emi_as_terminal;
+mod emi_filter;
+mod emi_filter_map;
+mod emi_inspect;
+mod emi_map;
+mod emi_map_while;
+mod emi_repeat_with;
+mod emi_take;
+mod emi_unfold;
 mod fmi_as_async;
+mod fmi_as_endless_or;
 mod fmi_as_iterator;
 mod fmi_as_terminal;
+mod fmi_chain;
+mod fmi_empty;
+mod fmi_filter;
+mod fmi_filter_map;
+mod fmi_from_fn;
+mod fmi_inspect;
+mod fmi_map;
+mod fmi_map_while;
+mod fmi_peekable;
+mod fmi_scan;
+mod fmi_successors;
+mod fmi_unfold;
+mod fmi_zip;
+mod fmi_zip_longest;
+#[cfg(feature = "futures")]
+mod forward_error;
+mod laemi_as_blocking;
+mod laemi_as_finite;
+mod laemi_as_terminal;
+mod laemi_filter_async;
+mod laemi_filter_map_async;
+mod laemi_then;
+mod lafmi_as_blocking;
+mod lafmi_as_endless_or;
+mod lafmi_as_terminal;
+mod lafmi_buffer_unordered;
+mod lafmi_buffered;
+mod lafmi_chain;
+mod lafmi_filter_async;
+mod lafmi_filter_map_async;
+mod lafmi_peekable;
+mod lafmi_scan;
+mod lafmi_then;
+mod lafmi_zip;
+mod lafmi_zip_longest;
+mod latmi_and_then_terminal;
+mod latmi_as_blocking;
+mod latmi_as_endless_or;
+mod latmi_as_finite_discarding;
+mod latmi_as_finite_with_slot;
+mod latmi_buffer_unordered;
+mod latmi_buffered;
+mod latmi_continue_with;
+mod latmi_filter_async;
+mod latmi_filter_map_async;
+mod latmi_map_err;
+mod latmi_map_terminal;
+mod latmi_peekable;
+mod latmi_scan;
+mod latmi_then;
+mod latmi_try_then;
+mod latmi_zip;
+mod latmi_zip_longest;
+#[cfg(feature = "futures")]
+mod stream_as_afmi;
+mod terminal_slot;
+#[cfg(feature = "tokio")]
+mod timeout_error;
+mod tmi_and_then_terminal;
 mod tmi_as_async;
+mod tmi_as_endless_or;
+mod tmi_as_finite_discarding;
+mod tmi_as_finite_with_slot;
+mod tmi_as_iterator;
+mod tmi_continue_with;
+mod tmi_filter;
+mod tmi_filter_map;
+mod tmi_from_fn;
+mod tmi_inspect;
+mod tmi_map;
+mod tmi_map_err;
+mod tmi_map_terminal;
+mod tmi_map_while;
+mod tmi_once_then;
+mod tmi_peekable;
+mod tmi_scan;
+mod tmi_skip_while;
+mod tmi_take;
+mod tmi_take_while;
+mod tmi_terminal_only;
+mod tmi_unfold;
+mod tmi_with_deadline;
+mod tmi_zip;
+mod tmi_zip_longest;
+#[cfg(feature = "futures")]
+mod try_stream_as_atmi;
+mod zip_terminal;
 
+pub use self::aemi_as_blocking::AemiAsBlocking;
 pub use self::aemi_as_finite::AemiAsFinite;
+pub use self::aemi_as_local::AemiAsLocal;
 pub use self::aemi_as_terminal::AemiAsTerminal;
+pub use self::aemi_filter_async::AemiFilterAsync;
+pub use self::aemi_filter_map_async::AemiFilterMapAsync;
+pub use self::aemi_repeat_with::AemiRepeatWith;
+#[cfg(feature = "futures")]
+pub use self::aemi_stream::AemiStream;
+pub use self::aemi_then::AemiThen;
+pub use self::aemi_unfold::AemiUnfold;
+pub use self::afmi_as_blocking::AfmiAsBlocking;
+pub use self::afmi_as_endless_or::AfmiAsEndlessOr;
+pub use self::afmi_as_local::AfmiAsLocal;
 pub use self::afmi_as_terminal::AfmiAsTerminal;
+pub use self::afmi_buffer_unordered::AfmiBufferUnordered;
+pub use self::afmi_buffered::AfmiBuffered;
+pub use self::afmi_chain::AfmiChain;
+pub use self::afmi_filter_async::AfmiFilterAsync;
+pub use self::afmi_filter_map_async::AfmiFilterMapAsync;
+pub use self::afmi_peekable::AfmiPeekable;
+pub use self::afmi_scan::AfmiScan;
+#[cfg(feature = "futures")]
+pub use self::afmi_stream::AfmiStream;
+pub use self::afmi_then::AfmiThen;
+pub use self::afmi_unfold::AfmiUnfold;
+pub use self::afmi_zip::AfmiZip;
+pub use self::afmi_zip_longest::AfmiZipLongest;
+pub use self::atmi_and_then_terminal::AtmiAndThenTerminal;
+pub use self::atmi_as_blocking::AtmiAsBlocking;
+pub use self::atmi_as_endless_or::AtmiAsEndlessOr;
+pub use self::atmi_as_finite_discarding::AtmiAsFiniteDiscarding;
+pub use self::atmi_as_finite_with_slot::AtmiAsFiniteWithSlot;
+pub use self::atmi_as_local::AtmiAsLocal;
+pub use self::atmi_buffer_unordered::AtmiBufferUnordered;
+pub use self::atmi_buffered::AtmiBuffered;
+pub use self::atmi_continue_with::AtmiContinueWith;
+pub use self::atmi_filter_async::AtmiFilterAsync;
+pub use self::atmi_filter_map_async::AtmiFilterMapAsync;
+pub use self::atmi_map_err::AtmiMapErr;
+pub use self::atmi_map_terminal::AtmiMapTerminal;
+pub use self::atmi_peekable::AtmiPeekable;
+pub use self::atmi_scan::AtmiScan;
+#[cfg(feature = "futures")]
+pub use self::atmi_stream::AtmiStream;
+pub use self::atmi_then::AtmiThen;
+#[cfg(feature = "futures")]
+pub use self::atmi_try_stream::AtmiTryStream;
+pub use self::atmi_try_then::AtmiTryThen;
+pub use self::atmi_unfold::AtmiUnfold;
+#[cfg(feature = "tokio")]
+pub use self::atmi_with_timeout::AtmiWithTimeout;
+pub use self::atmi_zip::AtmiZip;
+pub use self::atmi_zip_longest::AtmiZipLongest;
+pub use self::either_or_both::EitherOrBoth;
 pub use self::emi_as_async::EmiAsAsync;
 pub use self::emi_as_finite::EmiAsFinite;
 pub use self::emi_as_iterator::EmiAsIterator;
 pub use self::emi_as_terminal::EmiAsTerminal;
+pub use self::emi_filter::EmiFilter;
+pub use self::emi_filter_map::EmiFilterMap;
+pub use self::emi_inspect::EmiInspect;
+pub use self::emi_map::EmiMap;
+pub use self::emi_map_while::EmiMapWhile;
+pub use self::emi_repeat_with::EmiRepeatWith;
+pub use self::emi_take::EmiTake;
+pub use self::emi_unfold::EmiUnfold;
 pub use self::fmi_as_async::FmiAsAsync;
+pub use self::fmi_as_endless_or::FmiAsEndlessOr;
 pub use self::fmi_as_iterator::FmiAsIterator;
 pub use self::fmi_as_terminal::FmiAsTerminal;
+pub use self::fmi_chain::FmiChain;
+pub use self::fmi_empty::FmiEmpty;
+pub use self::fmi_filter::FmiFilter;
+pub use self::fmi_filter_map::FmiFilterMap;
+pub use self::fmi_from_fn::FmiFromFn;
+pub use self::fmi_inspect::FmiInspect;
+pub use self::fmi_map::FmiMap;
+pub use self::fmi_map_while::FmiMapWhile;
+pub use self::fmi_peekable::FmiPeekable;
+pub use self::fmi_scan::FmiScan;
+pub use self::fmi_successors::FmiSuccessors;
+pub use self::fmi_unfold::FmiUnfold;
+pub use self::fmi_zip::FmiZip;
+pub use self::fmi_zip_longest::FmiZipLongest;
+#[cfg(feature = "futures")]
+pub use self::forward_error::ForwardError;
+pub use self::laemi_as_blocking::LaemiAsBlocking;
+pub use self::laemi_as_finite::LaemiAsFinite;
+pub use self::laemi_as_terminal::LaemiAsTerminal;
+pub use self::laemi_filter_async::LaemiFilterAsync;
+pub use self::laemi_filter_map_async::LaemiFilterMapAsync;
+pub use self::laemi_then::LaemiThen;
+pub use self::lafmi_as_blocking::LafmiAsBlocking;
+pub use self::lafmi_as_endless_or::LafmiAsEndlessOr;
+pub use self::lafmi_as_terminal::LafmiAsTerminal;
+pub use self::lafmi_buffer_unordered::LafmiBufferUnordered;
+pub use self::lafmi_buffered::LafmiBuffered;
+pub use self::lafmi_chain::LafmiChain;
+pub use self::lafmi_filter_async::LafmiFilterAsync;
+pub use self::lafmi_filter_map_async::LafmiFilterMapAsync;
+pub use self::lafmi_peekable::LafmiPeekable;
+pub use self::lafmi_scan::LafmiScan;
+pub use self::lafmi_then::LafmiThen;
+pub use self::lafmi_zip::LafmiZip;
+pub use self::lafmi_zip_longest::LafmiZipLongest;
+pub use self::latmi_and_then_terminal::LatmiAndThenTerminal;
+pub use self::latmi_as_blocking::LatmiAsBlocking;
+pub use self::latmi_as_endless_or::LatmiAsEndlessOr;
+pub use self::latmi_as_finite_discarding::LatmiAsFiniteDiscarding;
+pub use self::latmi_as_finite_with_slot::LatmiAsFiniteWithSlot;
+pub use self::latmi_buffer_unordered::LatmiBufferUnordered;
+pub use self::latmi_buffered::LatmiBuffered;
+pub use self::latmi_continue_with::LatmiContinueWith;
+pub use self::latmi_filter_async::LatmiFilterAsync;
+pub use self::latmi_filter_map_async::LatmiFilterMapAsync;
+pub use self::latmi_map_err::LatmiMapErr;
+pub use self::latmi_map_terminal::LatmiMapTerminal;
+pub use self::latmi_peekable::LatmiPeekable;
+pub use self::latmi_scan::LatmiScan;
+pub use self::latmi_then::LatmiThen;
+pub use self::latmi_try_then::LatmiTryThen;
+pub use self::latmi_zip::LatmiZip;
+pub use self::latmi_zip_longest::LatmiZipLongest;
+#[cfg(feature = "futures")]
+pub use self::stream_as_afmi::StreamAsAfmi;
+pub use self::terminal_slot::TerminalSlot;
+#[cfg(feature = "tokio")]
+pub use self::timeout_error::TimeoutError;
+pub use self::tmi_and_then_terminal::TmiAndThenTerminal;
 pub use self::tmi_as_async::TmiAsAsync;
+pub use self::tmi_as_endless_or::TmiAsEndlessOr;
+pub use self::tmi_as_finite_discarding::TmiAsFiniteDiscarding;
+pub use self::tmi_as_finite_with_slot::TmiAsFiniteWithSlot;
+pub use self::tmi_as_iterator::TmiAsIterator;
+pub use self::tmi_continue_with::TmiContinueWith;
+pub use self::tmi_filter::TmiFilter;
+pub use self::tmi_filter_map::TmiFilterMap;
+pub use self::tmi_from_fn::TmiFromFn;
+pub use self::tmi_inspect::TmiInspect;
+pub use self::tmi_map::TmiMap;
+pub use self::tmi_map_err::TmiMapErr;
+pub use self::tmi_map_terminal::TmiMapTerminal;
+pub use self::tmi_map_while::TmiMapWhile;
+pub use self::tmi_once_then::TmiOnceThen;
+pub use self::tmi_peekable::TmiPeekable;
+pub use self::tmi_scan::TmiScan;
+pub use self::tmi_skip_while::TmiSkipWhile;
+pub use self::tmi_take::TmiTake;
+pub use self::tmi_take_while::TmiTakeWhile;
+pub use self::tmi_terminal_only::TmiTerminalOnly;
+pub use self::tmi_unfold::TmiUnfold;
+pub use self::tmi_with_deadline::TmiWithDeadline;
+pub use self::tmi_zip::TmiZip;
+pub use self::tmi_zip_longest::TmiZipLongest;
+#[cfg(feature = "futures")]
+pub use self::try_stream_as_atmi::TryStreamAsAtmi;
+pub use self::zip_terminal::ZipTerminal;
@@ -0,0 +1,19 @@
+use crate::blocking::BlockOn;
+use crate::{AsyncEndlessMoveIterator, EndlessMoveIterator};
+
+/// A [EndlessMoveIterator] impl from [AsyncEndlessMoveIterator::into_blocking] or [AsyncEndlessMoveIterator::into_blocking_with].
+pub struct AemiAsBlocking<I, B>(pub(crate) I, pub(crate) B);
+
+impl<I, B> EndlessMoveIterator for AemiAsBlocking<I, B>
+where
+    I: AsyncEndlessMoveIterator,
+    B: BlockOn,
+{
+    type Item = <I as AsyncEndlessMoveIterator>::Item;
+
+    fn into_next(self) -> (Self, Self::Item) {
+        let AemiAsBlocking(inner, executor) = self;
+        let (inner, x) = executor.block_on(AsyncEndlessMoveIterator::into_next(inner));
+        (AemiAsBlocking(inner, executor), x)
+    }
+}
@@ -1,10 +1,8 @@
 use crate::{AsyncEndlessMoveIterator, AsyncFiniteMoveIterator};
-use async_trait::async_trait;
 
 /// An [AsyncFiniteMoveIterator] impl from [AsyncEndlessMoveIterator::into_async_finite_move_iterator].
 pub struct AemiAsFinite<I>(pub(crate) I);
 
-#[async_trait]
 impl<I> AsyncFiniteMoveIterator for AemiAsFinite<I>
 where
     I: AsyncEndlessMoveIterator,
@@ -0,0 +1,16 @@
+use crate::{AsyncEndlessMoveIterator, LocalAsyncEndlessMoveIterator};
+
+/// A [LocalAsyncEndlessMoveIterator] impl from [AsyncEndlessMoveIterator::into_local].
+pub struct AemiAsLocal<I>(pub(crate) I);
+
+impl<I> LocalAsyncEndlessMoveIterator for AemiAsLocal<I>
+where
+    I: AsyncEndlessMoveIterator,
+{
+    type Item = <I as AsyncEndlessMoveIterator>::Item;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let (inner, x) = AsyncEndlessMoveIterator::into_next(self.0).await;
+        (AemiAsLocal(inner), x)
+    }
+}
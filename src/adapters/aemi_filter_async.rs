@@ -0,0 +1,29 @@
+use crate::AsyncEndlessMoveIterator;
+use std::future::Future;
+
+/// An [AsyncEndlessMoveIterator] impl from [AsyncEndlessMoveIterator::filter_async].
+///
+/// If the predicate never accepts another item, [AsyncEndlessMoveIterator::into_next] never
+/// completes.
+pub struct AemiFilterAsync<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P, Fut> AsyncEndlessMoveIterator for AemiFilterAsync<I, P>
+where
+    I: AsyncEndlessMoveIterator,
+    <I as AsyncEndlessMoveIterator>::Item: Send,
+    P: FnMut(&<I as AsyncEndlessMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = bool> + Send,
+{
+    type Item = <I as AsyncEndlessMoveIterator>::Item;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let AemiFilterAsync(mut inner, mut pred) = self;
+        loop {
+            let (next, x) = AsyncEndlessMoveIterator::into_next(inner).await;
+            if pred(&x).await {
+                return (AemiFilterAsync(next, pred), x);
+            }
+            inner = next;
+        }
+    }
+}
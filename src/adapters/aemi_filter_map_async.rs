@@ -0,0 +1,28 @@
+use crate::AsyncEndlessMoveIterator;
+use std::future::Future;
+
+/// An [AsyncEndlessMoveIterator] impl from [AsyncEndlessMoveIterator::filter_map_async].
+///
+/// If the closure never returns `Some` again, [AsyncEndlessMoveIterator::into_next] never
+/// completes.
+pub struct AemiFilterMapAsync<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> AsyncEndlessMoveIterator for AemiFilterMapAsync<I, F>
+where
+    I: AsyncEndlessMoveIterator,
+    F: FnMut(<I as AsyncEndlessMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = Option<B>> + Send,
+{
+    type Item = B;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let AemiFilterMapAsync(mut inner, mut f) = self;
+        loop {
+            let (next, x) = AsyncEndlessMoveIterator::into_next(inner).await;
+            if let Some(y) = f(x).await {
+                return (AemiFilterMapAsync(next, f), y);
+            }
+            inner = next;
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::AsyncEndlessMoveIterator;
+use std::future::Future;
+
+/// An [AsyncEndlessMoveIterator] impl from [repeat_with](crate::asyn::endless::repeat_with).
+pub struct AemiRepeatWith<F>(pub(crate) F);
+
+impl<F, Fut, B> AsyncEndlessMoveIterator for AemiRepeatWith<F>
+where
+    F: FnMut() -> Fut + Send,
+    Fut: Future<Output = B> + Send,
+{
+    type Item = B;
+
+    async fn into_next(mut self) -> (Self, Self::Item) {
+        let x = (self.0)().await;
+        (self, x)
+    }
+}
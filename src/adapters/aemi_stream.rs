@@ -0,0 +1,45 @@
+use crate::AsyncEndlessMoveIterator;
+use futures::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type Step<'a, I> =
+    Pin<Box<dyn Future<Output = (I, <I as AsyncEndlessMoveIterator>::Item)> + Send + 'a>>;
+
+/// A [Stream] impl from [AsyncEndlessMoveIterator::into_stream], which never ends.
+pub struct AemiStream<'a, I>
+where
+    I: AsyncEndlessMoveIterator,
+{
+    pending: Step<'a, I>,
+}
+
+impl<'a, I> AemiStream<'a, I>
+where
+    I: AsyncEndlessMoveIterator + 'a,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        AemiStream {
+            pending: Box::pin(AsyncEndlessMoveIterator::into_next(inner)),
+        }
+    }
+}
+
+impl<'a, I> Stream for AemiStream<'a, I>
+where
+    I: AsyncEndlessMoveIterator + 'a,
+{
+    type Item = <I as AsyncEndlessMoveIterator>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        match this.pending.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready((inner, x)) => {
+                this.pending = Box::pin(AsyncEndlessMoveIterator::into_next(inner));
+                Poll::Ready(Some(x))
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::AsyncEndlessMoveIterator;
+use std::future::Future;
+
+/// An [AsyncEndlessMoveIterator] impl from [AsyncEndlessMoveIterator::then].
+pub struct AemiThen<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> AsyncEndlessMoveIterator for AemiThen<I, F>
+where
+    I: AsyncEndlessMoveIterator,
+    F: FnMut(<I as AsyncEndlessMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = B> + Send,
+{
+    type Item = B;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let AemiThen(inner, mut f) = self;
+        let (inner, x) = AsyncEndlessMoveIterator::into_next(inner).await;
+        let y = f(x).await;
+        (AemiThen(inner, f), y)
+    }
+}
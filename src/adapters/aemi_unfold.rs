@@ -0,0 +1,20 @@
+use crate::AsyncEndlessMoveIterator;
+use std::future::Future;
+
+/// An [AsyncEndlessMoveIterator] impl from [unfold](crate::asyn::endless::unfold).
+pub struct AemiUnfold<S, F>(pub(crate) S, pub(crate) F);
+
+impl<S, F, Fut, B> AsyncEndlessMoveIterator for AemiUnfold<S, F>
+where
+    S: Send,
+    F: FnMut(S) -> Fut + Send,
+    Fut: Future<Output = (S, B)> + Send,
+{
+    type Item = B;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let AemiUnfold(state, mut f) = self;
+        let (state, x) = f(state).await;
+        (AemiUnfold(state, f), x)
+    }
+}
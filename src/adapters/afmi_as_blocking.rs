@@ -0,0 +1,21 @@
+use crate::blocking::BlockOn;
+use crate::{AsyncFiniteMoveIterator, FiniteMoveIterator};
+
+/// A [FiniteMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::into_blocking](crate::AsyncFiniteMoveIteratorExt::into_blocking) or
+/// [AsyncFiniteMoveIteratorExt::into_blocking_with](crate::AsyncFiniteMoveIteratorExt::into_blocking_with).
+pub struct AfmiAsBlocking<I, B>(pub(crate) I, pub(crate) B);
+
+impl<I, B> FiniteMoveIterator for AfmiAsBlocking<I, B>
+where
+    I: AsyncFiniteMoveIterator,
+    B: BlockOn,
+{
+    type Item = <I as AsyncFiniteMoveIterator>::Item;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        let AfmiAsBlocking(inner, executor) = self;
+        let (inner, x) = executor.block_on(AsyncFiniteMoveIterator::into_next(inner))?;
+        Some((AfmiAsBlocking(inner, executor), x))
+    }
+}
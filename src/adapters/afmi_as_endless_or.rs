@@ -0,0 +1,25 @@
+use crate::{AsyncEndlessMoveIterator, AsyncFiniteMoveIterator};
+
+/// An [AsyncEndlessMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::into_endless_or](crate::AsyncFiniteMoveIteratorExt::into_endless_or).
+pub struct AfmiAsEndlessOr<I, F>(pub(crate) Option<I>, pub(crate) F);
+
+impl<I, F> AsyncEndlessMoveIterator for AfmiAsEndlessOr<I, F>
+where
+    I: AsyncFiniteMoveIterator,
+    F: FnMut() -> <I as AsyncFiniteMoveIterator>::Item + Send,
+{
+    type Item = <I as AsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let AfmiAsEndlessOr(inner, mut fallback) = self;
+        if let Some(inner) = inner {
+            if let Some((inner, x)) = AsyncFiniteMoveIterator::into_next(inner).await {
+                return (AfmiAsEndlessOr(Some(inner), fallback), x);
+            }
+        }
+
+        let x = fallback();
+        (AfmiAsEndlessOr(None, fallback), x)
+    }
+}
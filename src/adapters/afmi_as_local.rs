@@ -0,0 +1,17 @@
+use crate::{AsyncFiniteMoveIterator, LocalAsyncFiniteMoveIterator};
+
+/// A [LocalAsyncFiniteMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::into_local](crate::AsyncFiniteMoveIteratorExt::into_local).
+pub struct AfmiAsLocal<I>(pub(crate) I);
+
+impl<I> LocalAsyncFiniteMoveIterator for AfmiAsLocal<I>
+where
+    I: AsyncFiniteMoveIterator,
+{
+    type Item = <I as AsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let (inner, x) = AsyncFiniteMoveIterator::into_next(self.0).await?;
+        Some((AfmiAsLocal(inner), x))
+    }
+}
@@ -1,11 +1,9 @@
 use crate::{AsyncFiniteMoveIterator, AsyncTerminalMoveIterator};
-use async_trait::async_trait;
 use either::Either;
 
 /// An [AsyncTerminalMoveIterator] impl from [AsyncFiniteMoveIterator::into_async_terminal_move_iterator].
 pub struct AfmiAsTerminal<I>(pub(crate) I);
 
-#[async_trait]
 impl<I> AsyncTerminalMoveIterator for AfmiAsTerminal<I>
 where
     I: AsyncFiniteMoveIterator,
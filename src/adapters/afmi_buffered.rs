@@ -0,0 +1,75 @@
+use crate::futpool::FuturePool;
+use crate::AsyncFiniteMoveIterator;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+
+// A step of the source, boxed so that it can stay in flight between calls to `into_next`:
+type Step<'a, I> =
+    Pin<Box<dyn Future<Output = Option<(I, <I as AsyncFiniteMoveIterator>::Item)>> + Send + 'a>>;
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::buffered](crate::AsyncFiniteMoveIteratorExt::buffered).
+pub struct AfmiBuffered<'a, I>
+where
+    I: AsyncFiniteMoveIterator,
+    <I as AsyncFiniteMoveIterator>::Item: Future,
+{
+    // The source step in flight, or `None` once the source has ended:
+    rest: Option<Step<'a, I>>,
+    pool: FuturePool<<I as AsyncFiniteMoveIterator>::Item>,
+    limit: usize,
+}
+
+impl<'a, I> AfmiBuffered<'a, I>
+where
+    I: AsyncFiniteMoveIterator + 'a,
+    <I as AsyncFiniteMoveIterator>::Item: Future,
+{
+    pub(crate) fn new(inner: I, limit: usize) -> Self {
+        assert!(limit > 0, "buffered requires a limit of at least 1");
+        AfmiBuffered {
+            rest: Some(Box::pin(AsyncFiniteMoveIterator::into_next(inner))),
+            pool: FuturePool::new(),
+            limit,
+        }
+    }
+}
+
+impl<'a, I> AsyncFiniteMoveIterator for AfmiBuffered<'a, I>
+where
+    I: AsyncFiniteMoveIterator + 'a,
+    <I as AsyncFiniteMoveIterator>::Item: Future + Send,
+    <<I as AsyncFiniteMoveIterator>::Item as Future>::Output: Send,
+{
+    type Item = <<I as AsyncFiniteMoveIterator>::Item as Future>::Output;
+
+    async fn into_next(mut self) -> Option<(Self, Self::Item)> {
+        // Race the source against the pool, so that finished futures are produced even while the
+        // source is pending:
+        let next = poll_fn(|cx| {
+            while self.pool.len() < self.limit {
+                let step = match &mut self.rest {
+                    Some(step) => step,
+                    None => break,
+                };
+                match step.as_mut().poll(cx) {
+                    Poll::Ready(Some((inner, fut))) => {
+                        self.pool.push(fut);
+                        self.rest = Some(Box::pin(AsyncFiniteMoveIterator::into_next(inner)));
+                    }
+                    Poll::Ready(None) => self.rest = None,
+                    Poll::Pending => break,
+                }
+            }
+
+            match self.pool.poll_next_ordered(cx) {
+                Poll::Ready(None) if self.rest.is_some() => Poll::Pending,
+                other => other,
+            }
+        })
+        .await;
+
+        next.map(|x| (self, x))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::AsyncFiniteMoveIterator;
+use either::Either;
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::chain](crate::AsyncFiniteMoveIteratorExt::chain).
+pub struct AfmiChain<I, J>(pub(crate) Either<(I, J), J>);
+
+impl<I, J> AsyncFiniteMoveIterator for AfmiChain<I, J>
+where
+    I: AsyncFiniteMoveIterator,
+    J: AsyncFiniteMoveIterator<Item = <I as AsyncFiniteMoveIterator>::Item>,
+{
+    type Item = <I as AsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        use Either::*;
+
+        let second = match self.0 {
+            Left((first, second)) => match AsyncFiniteMoveIterator::into_next(first).await {
+                Some((first, x)) => {
+                    return Some((AfmiChain(Left((first, second))), x));
+                }
+                None => second,
+            },
+            Right(second) => second,
+        };
+
+        AsyncFiniteMoveIterator::into_next(second)
+            .await
+            .map(|(second, x)| (AfmiChain(Right(second)), x))
+    }
+}
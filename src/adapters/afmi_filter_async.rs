@@ -0,0 +1,27 @@
+use crate::AsyncFiniteMoveIterator;
+use std::future::Future;
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::filter_async](crate::AsyncFiniteMoveIteratorExt::filter_async).
+pub struct AfmiFilterAsync<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P, Fut> AsyncFiniteMoveIterator for AfmiFilterAsync<I, P>
+where
+    I: AsyncFiniteMoveIterator,
+    <I as AsyncFiniteMoveIterator>::Item: Send,
+    P: FnMut(&<I as AsyncFiniteMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = bool> + Send,
+{
+    type Item = <I as AsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let AfmiFilterAsync(mut inner, mut pred) = self;
+        loop {
+            let (next, x) = AsyncFiniteMoveIterator::into_next(inner).await?;
+            if pred(&x).await {
+                return Some((AfmiFilterAsync(next, pred), x));
+            }
+            inner = next;
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::AsyncFiniteMoveIterator;
+use std::future::Future;
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::filter_map_async](crate::AsyncFiniteMoveIteratorExt::filter_map_async).
+pub struct AfmiFilterMapAsync<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> AsyncFiniteMoveIterator for AfmiFilterMapAsync<I, F>
+where
+    I: AsyncFiniteMoveIterator,
+    F: FnMut(<I as AsyncFiniteMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = Option<B>> + Send,
+{
+    type Item = B;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let AfmiFilterMapAsync(mut inner, mut f) = self;
+        loop {
+            let (next, x) = AsyncFiniteMoveIterator::into_next(inner).await?;
+            if let Some(y) = f(x).await {
+                return Some((AfmiFilterMapAsync(next, f), y));
+            }
+            inner = next;
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::AsyncFiniteMoveIterator;
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::peekable](crate::AsyncFiniteMoveIteratorExt::peekable) which
+/// supports lookahead and push-back.
+pub struct AfmiPeekable<I>
+where
+    I: AsyncFiniteMoveIterator,
+{
+    // Pushed back or peeked items, with the next item last:
+    front: Vec<<I as AsyncFiniteMoveIterator>::Item>,
+    // This is `None` once `I` has ended:
+    rest: Option<I>,
+}
+
+impl<I> AfmiPeekable<I>
+where
+    I: AsyncFiniteMoveIterator,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        AfmiPeekable {
+            front: vec![],
+            rest: Some(inner),
+        }
+    }
+
+    /// Look ahead at the next `Item` without consuming it, which is then available from
+    /// [AfmiPeekable::peeked].
+    ///
+    /// This moves `self`, as [AsyncFiniteMoveIterator::into_next] does, so dropping the returned
+    /// future drops the iterator rather than leaving it partially advanced.
+    pub async fn into_peek(self) -> Self {
+        let AfmiPeekable { mut front, rest } = self;
+        let rest =
+            match rest {
+                Some(inner) if front.is_empty() => AsyncFiniteMoveIterator::into_next(inner)
+                    .await
+                    .map(|(inner, x)| {
+                        front.push(x);
+                        inner
+                    }),
+                other => other,
+            };
+
+        AfmiPeekable { front, rest }
+    }
+
+    /// Return a reference to the next `Item` if it is known. After [AfmiPeekable::into_peek],
+    /// `None` means the end.
+    pub fn peeked(&self) -> Option<&<I as AsyncFiniteMoveIterator>::Item> {
+        self.front.last()
+    }
+
+    /// Put `item` in front, so that it is the next `Item` produced.
+    pub fn push_back(&mut self, item: <I as AsyncFiniteMoveIterator>::Item) {
+        self.front.push(item);
+    }
+}
+
+impl<I> AsyncFiniteMoveIterator for AfmiPeekable<I>
+where
+    I: AsyncFiniteMoveIterator,
+    <I as AsyncFiniteMoveIterator>::Item: Send,
+{
+    type Item = <I as AsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let AfmiPeekable { mut front, rest } = self;
+        if let Some(x) = front.pop() {
+            return Some((AfmiPeekable { front, rest }, x));
+        }
+
+        let (inner, x) = AsyncFiniteMoveIterator::into_next(rest?).await?;
+        Some((
+            AfmiPeekable {
+                front,
+                rest: Some(inner),
+            },
+            x,
+        ))
+    }
+}
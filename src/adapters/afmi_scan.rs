@@ -0,0 +1,43 @@
+use crate::{AsyncFiniteMoveIterator, AsyncTerminalMoveIterator};
+use either::Either;
+use std::future::Future;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::scan](crate::AsyncFiniteMoveIteratorExt::scan).
+pub struct AfmiScan<I, S, F>(pub(crate) I, pub(crate) S, pub(crate) F);
+
+impl<I, S, F, Fut, B> AsyncTerminalMoveIterator for AfmiScan<I, S, F>
+where
+    I: AsyncFiniteMoveIterator,
+    S: Send,
+    F: FnMut(S, <I as AsyncFiniteMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = (S, Option<B>)> + Send,
+{
+    type Terminal = S;
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AfmiScan(mut inner, mut state, mut f) = self;
+        loop {
+            let (next, x) = match AsyncFiniteMoveIterator::into_next(inner).await {
+                Some(next) => next,
+                None => {
+                    return Right(state);
+                }
+            };
+
+            let (s, opty) = f(state, x).await;
+            state = s;
+            match opty {
+                Some(y) => {
+                    return Left((AfmiScan(next, state, f), y));
+                }
+                None => {
+                    inner = next;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::AsyncFiniteMoveIterator;
+use futures::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type Step<'a, I> =
+    Pin<Box<dyn Future<Output = Option<(I, <I as AsyncFiniteMoveIterator>::Item)>> + Send + 'a>>;
+
+/// A [Stream] impl from
+/// [AsyncFiniteMoveIteratorExt::into_stream](crate::AsyncFiniteMoveIteratorExt::into_stream).
+pub struct AfmiStream<'a, I>
+where
+    I: AsyncFiniteMoveIterator,
+{
+    pending: Option<Step<'a, I>>,
+}
+
+impl<'a, I> AfmiStream<'a, I>
+where
+    I: AsyncFiniteMoveIterator + 'a,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        AfmiStream {
+            pending: Some(Box::pin(AsyncFiniteMoveIterator::into_next(inner))),
+        }
+    }
+}
+
+impl<'a, I> Stream for AfmiStream<'a, I>
+where
+    I: AsyncFiniteMoveIterator + 'a,
+{
+    type Item = <I as AsyncFiniteMoveIterator>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let pending = match this.pending.as_mut() {
+            Some(pending) => pending,
+            None => return Poll::Ready(None),
+        };
+
+        match pending.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Some((inner, x))) => {
+                this.pending = Some(Box::pin(AsyncFiniteMoveIterator::into_next(inner)));
+                Poll::Ready(Some(x))
+            }
+            Poll::Ready(None) => {
+                this.pending = None;
+                Poll::Ready(None)
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::AsyncFiniteMoveIterator;
+use std::future::Future;
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::then](crate::AsyncFiniteMoveIteratorExt::then).
+pub struct AfmiThen<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> AsyncFiniteMoveIterator for AfmiThen<I, F>
+where
+    I: AsyncFiniteMoveIterator,
+    F: FnMut(<I as AsyncFiniteMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = B> + Send,
+{
+    type Item = B;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let AfmiThen(inner, mut f) = self;
+        let (inner, x) = AsyncFiniteMoveIterator::into_next(inner).await?;
+        let y = f(x).await;
+        Some((AfmiThen(inner, f), y))
+    }
+}
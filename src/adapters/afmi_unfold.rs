@@ -0,0 +1,20 @@
+use crate::AsyncFiniteMoveIterator;
+use std::future::Future;
+
+/// An [AsyncFiniteMoveIterator] impl from [unfold](crate::asyn::finite::unfold).
+pub struct AfmiUnfold<S, F>(pub(crate) S, pub(crate) F);
+
+impl<S, F, Fut, B> AsyncFiniteMoveIterator for AfmiUnfold<S, F>
+where
+    S: Send,
+    F: FnMut(S) -> Fut + Send,
+    Fut: Future<Output = Option<(S, B)>> + Send,
+{
+    type Item = B;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let AfmiUnfold(state, mut f) = self;
+        let (state, x) = f(state).await?;
+        Some((AfmiUnfold(state, f), x))
+    }
+}
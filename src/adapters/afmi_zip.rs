@@ -0,0 +1,44 @@
+use crate::adapters::ZipTerminal;
+use crate::{AsyncFiniteMoveIterator, AsyncTerminalMoveIterator};
+use either::Either;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::zip](crate::AsyncFiniteMoveIteratorExt::zip).
+pub struct AfmiZip<A, B>(pub(crate) A, pub(crate) B);
+
+impl<A, B> AsyncTerminalMoveIterator for AfmiZip<A, B>
+where
+    A: AsyncFiniteMoveIterator,
+    B: AsyncFiniteMoveIterator,
+    <A as AsyncFiniteMoveIterator>::Item: Send,
+{
+    type Terminal = ZipTerminal<A, <A as AsyncFiniteMoveIterator>::Item, (), B, ()>;
+    type Item = (
+        <A as AsyncFiniteMoveIterator>::Item,
+        <B as AsyncFiniteMoveIterator>::Item,
+    );
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AfmiZip(a, b) = self;
+        let (a, xa) = match AsyncFiniteMoveIterator::into_next(a).await {
+            Some(next) => next,
+            None => {
+                return Right(ZipTerminal::LeftEnded {
+                    terminal: (),
+                    right: b,
+                });
+            }
+        };
+
+        match AsyncFiniteMoveIterator::into_next(b).await {
+            Some((b, xb)) => Left((AfmiZip(a, b), (xa, xb))),
+            None => Right(ZipTerminal::RightEnded {
+                left: a,
+                pending: xa,
+                terminal: (),
+            }),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::adapters::EitherOrBoth;
+use crate::AsyncFiniteMoveIterator;
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [AsyncFiniteMoveIteratorExt::zip_longest](crate::AsyncFiniteMoveIteratorExt::zip_longest).
+pub struct AfmiZipLongest<A, B>(ZipLongestState<A, B>);
+
+enum ZipLongestState<A, B> {
+    Both(A, B),
+    LeftOnly(A),
+    RightOnly(B),
+}
+
+impl<A, B> AfmiZipLongest<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        AfmiZipLongest(ZipLongestState::Both(a, b))
+    }
+}
+
+impl<A, B> AsyncFiniteMoveIterator for AfmiZipLongest<A, B>
+where
+    A: AsyncFiniteMoveIterator,
+    B: AsyncFiniteMoveIterator,
+    <A as AsyncFiniteMoveIterator>::Item: Send,
+{
+    type Item =
+        EitherOrBoth<<A as AsyncFiniteMoveIterator>::Item, <B as AsyncFiniteMoveIterator>::Item>;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        use ZipLongestState::*;
+
+        match self.0 {
+            Both(a, b) => {
+                let nexta = AsyncFiniteMoveIterator::into_next(a).await;
+                match (nexta, AsyncFiniteMoveIterator::into_next(b).await) {
+                    (Some((a, xa)), Some((b, xb))) => {
+                        Some((AfmiZipLongest(Both(a, b)), EitherOrBoth::Both(xa, xb)))
+                    }
+                    (Some((a, xa)), None) => {
+                        Some((AfmiZipLongest(LeftOnly(a)), EitherOrBoth::Left(xa)))
+                    }
+                    (None, Some((b, xb))) => {
+                        Some((AfmiZipLongest(RightOnly(b)), EitherOrBoth::Right(xb)))
+                    }
+                    (None, None) => None,
+                }
+            }
+            LeftOnly(a) => AsyncFiniteMoveIterator::into_next(a)
+                .await
+                .map(|(a, xa)| (AfmiZipLongest(LeftOnly(a)), EitherOrBoth::Left(xa))),
+            RightOnly(b) => AsyncFiniteMoveIterator::into_next(b)
+                .await
+                .map(|(b, xb)| (AfmiZipLongest(RightOnly(b)), EitherOrBoth::Right(xb))),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::and_then_terminal](crate::AsyncTerminalMoveIteratorExt::and_then_terminal).
+pub struct AtmiAndThenTerminal<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, R> AsyncTerminalMoveIterator for AtmiAndThenTerminal<I, F>
+where
+    I: AsyncTerminalMoveIterator,
+    F: FnMut(<I as AsyncTerminalMoveIterator>::Terminal) -> Either<I, R> + Send,
+{
+    type Terminal = R;
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiAndThenTerminal(mut inner, mut f) = self;
+        loop {
+            match AsyncTerminalMoveIterator::into_next(inner).await {
+                Left((next, x)) => {
+                    return Left((AtmiAndThenTerminal(next, f), x));
+                }
+                Right(term) => match f(term) {
+                    Left(next) => {
+                        inner = next;
+                    }
+                    Right(r) => {
+                        return Right(r);
+                    }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::blocking::BlockOn;
+use crate::{AsyncTerminalMoveIterator, TerminalMoveIterator};
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::into_blocking](crate::AsyncTerminalMoveIteratorExt::into_blocking)
+/// or
+/// [AsyncTerminalMoveIteratorExt::into_blocking_with](crate::AsyncTerminalMoveIteratorExt::into_blocking_with).
+pub struct AtmiAsBlocking<I, B>(pub(crate) I, pub(crate) B);
+
+impl<I, B> TerminalMoveIterator for AtmiAsBlocking<I, B>
+where
+    I: AsyncTerminalMoveIterator,
+    B: BlockOn,
+{
+    type Terminal = <I as AsyncTerminalMoveIterator>::Terminal;
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiAsBlocking(inner, executor) = self;
+        match executor.block_on(AsyncTerminalMoveIterator::into_next(inner)) {
+            Left((inner, x)) => Left((AtmiAsBlocking(inner, executor), x)),
+            Right(term) => Right(term),
+        }
+    }
+}
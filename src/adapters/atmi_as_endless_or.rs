@@ -0,0 +1,37 @@
+use crate::{AsyncEndlessMoveIterator, AsyncTerminalMoveIterator};
+use either::Either;
+
+/// An [AsyncEndlessMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::into_endless_or](crate::AsyncTerminalMoveIteratorExt::into_endless_or).
+pub struct AtmiAsEndlessOr<I, F>(
+    pub(crate) Either<I, <I as AsyncTerminalMoveIterator>::Terminal>,
+    pub(crate) F,
+)
+where
+    I: AsyncTerminalMoveIterator;
+
+impl<I, F> AsyncEndlessMoveIterator for AtmiAsEndlessOr<I, F>
+where
+    I: AsyncTerminalMoveIterator,
+    <I as AsyncTerminalMoveIterator>::Terminal: Send,
+    F: FnMut(&<I as AsyncTerminalMoveIterator>::Terminal) -> <I as AsyncTerminalMoveIterator>::Item
+        + Send,
+{
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        use Either::*;
+
+        let AtmiAsEndlessOr(state, mut fallback) = self;
+        let state = match state {
+            Left(inner) => match AsyncTerminalMoveIterator::into_next(inner).await {
+                Left((inner, x)) => return (AtmiAsEndlessOr(Left(inner), fallback), x),
+                Right(term) => term,
+            },
+            Right(term) => term,
+        };
+
+        let x = fallback(&state);
+        (AtmiAsEndlessOr(Right(state), fallback), x)
+    }
+}
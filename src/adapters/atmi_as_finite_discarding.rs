@@ -0,0 +1,19 @@
+use crate::{AsyncFiniteMoveIterator, AsyncTerminalMoveIterator};
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::into_finite_discarding_terminal](crate::AsyncTerminalMoveIteratorExt::into_finite_discarding_terminal).
+pub struct AtmiAsFiniteDiscarding<I>(pub(crate) I);
+
+impl<I> AsyncFiniteMoveIterator for AtmiAsFiniteDiscarding<I>
+where
+    I: AsyncTerminalMoveIterator,
+{
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        AsyncTerminalMoveIterator::into_next(self.0)
+            .await
+            .left()
+            .map(|(inner, x)| (AtmiAsFiniteDiscarding(inner), x))
+    }
+}
@@ -0,0 +1,33 @@
+use crate::adapters::TerminalSlot;
+use crate::{AsyncFiniteMoveIterator, AsyncTerminalMoveIterator};
+use either::Either;
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::into_finite_with_terminal_slot](crate::AsyncTerminalMoveIteratorExt::into_finite_with_terminal_slot).
+pub struct AtmiAsFiniteWithSlot<I>(
+    pub(crate) I,
+    pub(crate) TerminalSlot<<I as AsyncTerminalMoveIterator>::Terminal>,
+)
+where
+    I: AsyncTerminalMoveIterator;
+
+impl<I> AsyncFiniteMoveIterator for AtmiAsFiniteWithSlot<I>
+where
+    I: AsyncTerminalMoveIterator,
+    <I as AsyncTerminalMoveIterator>::Terminal: Send,
+{
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        use Either::*;
+
+        let AtmiAsFiniteWithSlot(inner, slot) = self;
+        match AsyncTerminalMoveIterator::into_next(inner).await {
+            Left((inner, x)) => Some((AtmiAsFiniteWithSlot(inner, slot), x)),
+            Right(term) => {
+                slot.set(term);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{AsyncTerminalMoveIterator, LocalAsyncTerminalMoveIterator};
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::into_local](crate::AsyncTerminalMoveIteratorExt::into_local).
+pub struct AtmiAsLocal<I>(pub(crate) I);
+
+impl<I> LocalAsyncTerminalMoveIterator for AtmiAsLocal<I>
+where
+    I: AsyncTerminalMoveIterator,
+{
+    type Terminal = <I as AsyncTerminalMoveIterator>::Terminal;
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        match AsyncTerminalMoveIterator::into_next(self.0).await {
+            Left((inner, x)) => Left((AtmiAsLocal(inner), x)),
+            Right(term) => Right(term),
+        }
+    }
+}
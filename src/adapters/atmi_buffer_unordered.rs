@@ -0,0 +1,95 @@
+use crate::futpool::FuturePool;
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+
+// A step of the source, boxed so that it can stay in flight between calls to `into_next`:
+type Step<'a, I> = Pin<
+    Box<
+        dyn Future<
+                Output = Either<
+                    (I, <I as AsyncTerminalMoveIterator>::Item),
+                    <I as AsyncTerminalMoveIterator>::Terminal,
+                >,
+            > + Send
+            + 'a,
+    >,
+>;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::buffer_unordered](crate::AsyncTerminalMoveIteratorExt::buffer_unordered).
+pub struct AtmiBufferUnordered<'a, I>
+where
+    I: AsyncTerminalMoveIterator,
+    <I as AsyncTerminalMoveIterator>::Item: Future,
+{
+    // The source step in flight, or the `Terminal` once the source has ended:
+    rest: Either<Step<'a, I>, <I as AsyncTerminalMoveIterator>::Terminal>,
+    pool: FuturePool<<I as AsyncTerminalMoveIterator>::Item>,
+    limit: usize,
+}
+
+impl<'a, I> AtmiBufferUnordered<'a, I>
+where
+    I: AsyncTerminalMoveIterator + 'a,
+    <I as AsyncTerminalMoveIterator>::Item: Future,
+{
+    pub(crate) fn new(inner: I, limit: usize) -> Self {
+        assert!(limit > 0, "buffer_unordered requires a limit of at least 1");
+        AtmiBufferUnordered {
+            rest: Either::Left(Box::pin(AsyncTerminalMoveIterator::into_next(inner))),
+            pool: FuturePool::new(),
+            limit,
+        }
+    }
+}
+
+impl<'a, I> AsyncTerminalMoveIterator for AtmiBufferUnordered<'a, I>
+where
+    I: AsyncTerminalMoveIterator + 'a,
+    <I as AsyncTerminalMoveIterator>::Terminal: Send,
+    <I as AsyncTerminalMoveIterator>::Item: Future + Send,
+    <<I as AsyncTerminalMoveIterator>::Item as Future>::Output: Send,
+{
+    type Terminal = <I as AsyncTerminalMoveIterator>::Terminal;
+    type Item = <<I as AsyncTerminalMoveIterator>::Item as Future>::Output;
+
+    async fn into_next(mut self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        // Race the source against the pool, so that finished futures are produced even while the
+        // source is pending:
+        let next = poll_fn(|cx| {
+            while self.pool.len() < self.limit {
+                let step = match &mut self.rest {
+                    Left(step) => step,
+                    Right(_) => break,
+                };
+                match step.as_mut().poll(cx) {
+                    Poll::Ready(Left((inner, fut))) => {
+                        self.pool.push(fut);
+                        self.rest = Left(Box::pin(AsyncTerminalMoveIterator::into_next(inner)));
+                    }
+                    Poll::Ready(Right(term)) => self.rest = Right(term),
+                    Poll::Pending => break,
+                }
+            }
+
+            match self.pool.poll_next_unordered(cx) {
+                Poll::Ready(None) if self.rest.is_left() => Poll::Pending,
+                other => other,
+            }
+        })
+        .await;
+
+        match next {
+            Some(x) => Left((self, x)),
+            None => match self.rest {
+                Right(term) => Right(term),
+                Left(_) => unreachable!("the pool only empties after the source terminates"),
+            },
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::continue_with](crate::AsyncTerminalMoveIteratorExt::continue_with).
+pub struct AtmiContinueWith<I, F, J>(pub(crate) Either<(I, F), J>);
+
+impl<I, F, J> AsyncTerminalMoveIterator for AtmiContinueWith<I, F, J>
+where
+    I: AsyncTerminalMoveIterator,
+    F: FnOnce(<I as AsyncTerminalMoveIterator>::Terminal) -> J + Send,
+    J: AsyncTerminalMoveIterator<Item = <I as AsyncTerminalMoveIterator>::Item>,
+{
+    type Terminal = <J as AsyncTerminalMoveIterator>::Terminal;
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let second = match self.0 {
+            Left((first, f)) => match AsyncTerminalMoveIterator::into_next(first).await {
+                Left((first, x)) => {
+                    return Left((AtmiContinueWith(Left((first, f))), x));
+                }
+                Right(term) => f(term),
+            },
+            Right(second) => second,
+        };
+
+        match AsyncTerminalMoveIterator::into_next(second).await {
+            Left((second, x)) => Left((AtmiContinueWith(Right(second)), x)),
+            Right(term) => Right(term),
+        }
+    }
+}
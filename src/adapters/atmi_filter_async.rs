@@ -0,0 +1,37 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::filter_async](crate::AsyncTerminalMoveIteratorExt::filter_async).
+pub struct AtmiFilterAsync<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P, Fut> AsyncTerminalMoveIterator for AtmiFilterAsync<I, P>
+where
+    I: AsyncTerminalMoveIterator,
+    <I as AsyncTerminalMoveIterator>::Item: Send,
+    P: FnMut(&<I as AsyncTerminalMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = bool> + Send,
+{
+    type Terminal = <I as AsyncTerminalMoveIterator>::Terminal;
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiFilterAsync(mut inner, mut pred) = self;
+        loop {
+            let (next, x) = match AsyncTerminalMoveIterator::into_next(inner).await {
+                Left(next) => next,
+                Right(term) => {
+                    return Right(term);
+                }
+            };
+
+            if pred(&x).await {
+                return Left((AtmiFilterAsync(next, pred), x));
+            }
+            inner = next;
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::filter_map_async](crate::AsyncTerminalMoveIteratorExt::filter_map_async).
+pub struct AtmiFilterMapAsync<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> AsyncTerminalMoveIterator for AtmiFilterMapAsync<I, F>
+where
+    I: AsyncTerminalMoveIterator,
+    F: FnMut(<I as AsyncTerminalMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = Option<B>> + Send,
+{
+    type Terminal = <I as AsyncTerminalMoveIterator>::Terminal;
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiFilterMapAsync(mut inner, mut f) = self;
+        loop {
+            let (next, x) = match AsyncTerminalMoveIterator::into_next(inner).await {
+                Left(next) => next,
+                Right(term) => {
+                    return Right(term);
+                }
+            };
+
+            if let Some(y) = f(x).await {
+                return Left((AtmiFilterMapAsync(next, f), y));
+            }
+            inner = next;
+        }
+    }
+}
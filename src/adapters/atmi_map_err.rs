@@ -0,0 +1,26 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::map_err](crate::AsyncTerminalMoveIteratorExt::map_err) or
+/// [AsyncTerminalMoveIteratorExt::err_into](crate::AsyncTerminalMoveIteratorExt::err_into).
+pub struct AtmiMapErr<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, T, E, E2> AsyncTerminalMoveIterator for AtmiMapErr<I, F>
+where
+    I: AsyncTerminalMoveIterator<Terminal = Result<T, E>>,
+    F: FnOnce(E) -> E2 + Send,
+{
+    type Terminal = Result<T, E2>;
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiMapErr(inner, f) = self;
+        match AsyncTerminalMoveIterator::into_next(inner).await {
+            Left((inner, x)) => Left((AtmiMapErr(inner, f), x)),
+            Right(term) => Right(term.map_err(f)),
+        }
+    }
+}
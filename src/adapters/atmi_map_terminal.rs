@@ -0,0 +1,25 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::map_terminal](crate::AsyncTerminalMoveIteratorExt::map_terminal).
+pub struct AtmiMapTerminal<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, R> AsyncTerminalMoveIterator for AtmiMapTerminal<I, F>
+where
+    I: AsyncTerminalMoveIterator,
+    F: FnOnce(<I as AsyncTerminalMoveIterator>::Terminal) -> R + Send,
+{
+    type Terminal = R;
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiMapTerminal(inner, f) = self;
+        match AsyncTerminalMoveIterator::into_next(inner).await {
+            Left((inner, x)) => Left((AtmiMapTerminal(inner, f), x)),
+            Right(term) => Right(f(term)),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::peekable](crate::AsyncTerminalMoveIteratorExt::peekable) which
+/// supports lookahead and push-back.
+pub struct AtmiPeekable<I>
+where
+    I: AsyncTerminalMoveIterator,
+{
+    // Pushed back or peeked items, with the next item last:
+    front: Vec<<I as AsyncTerminalMoveIterator>::Item>,
+    rest: Either<I, <I as AsyncTerminalMoveIterator>::Terminal>,
+}
+
+impl<I> AtmiPeekable<I>
+where
+    I: AsyncTerminalMoveIterator,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        AtmiPeekable {
+            front: vec![],
+            rest: Either::Left(inner),
+        }
+    }
+
+    /// Look ahead at the next `Item` or the `Terminal` without consuming it, which is then
+    /// available from [AtmiPeekable::peeked].
+    ///
+    /// This moves `self`, as [AsyncTerminalMoveIterator::into_next] does, so dropping the
+    /// returned future drops the iterator rather than leaving it partially advanced.
+    pub async fn into_peek(self) -> Self {
+        use Either::*;
+
+        let AtmiPeekable { mut front, rest } = self;
+        let rest = match rest {
+            Left(inner) if front.is_empty() => {
+                match AsyncTerminalMoveIterator::into_next(inner).await {
+                    Left((inner, x)) => {
+                        front.push(x);
+                        Left(inner)
+                    }
+                    Right(term) => Right(term),
+                }
+            }
+            other => other,
+        };
+
+        AtmiPeekable { front, rest }
+    }
+
+    /// Return a reference to either the next `Item` or the `Terminal` if it is known, which it
+    /// always is after [AtmiPeekable::into_peek] or [AtmiPeekable::push_back].
+    pub fn peeked(
+        &self,
+    ) -> Option<
+        Either<
+            &<I as AsyncTerminalMoveIterator>::Item,
+            &<I as AsyncTerminalMoveIterator>::Terminal,
+        >,
+    > {
+        use Either::*;
+
+        match (self.front.last(), &self.rest) {
+            (Some(x), _) => Some(Left(x)),
+            (None, Right(term)) => Some(Right(term)),
+            (None, Left(_)) => None,
+        }
+    }
+
+    /// Put `item` in front, so that it is the next `Item` produced.
+    pub fn push_back(&mut self, item: <I as AsyncTerminalMoveIterator>::Item) {
+        self.front.push(item);
+    }
+}
+
+impl<I> AsyncTerminalMoveIterator for AtmiPeekable<I>
+where
+    I: AsyncTerminalMoveIterator,
+    <I as AsyncTerminalMoveIterator>::Item: Send,
+    <I as AsyncTerminalMoveIterator>::Terminal: Send,
+{
+    type Terminal = <I as AsyncTerminalMoveIterator>::Terminal;
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiPeekable { mut front, rest } = self;
+        if let Some(x) = front.pop() {
+            return Left((AtmiPeekable { front, rest }, x));
+        }
+
+        let inner = match rest {
+            Left(inner) => inner,
+            Right(term) => {
+                return Right(term);
+            }
+        };
+
+        match AsyncTerminalMoveIterator::into_next(inner).await {
+            Left((inner, x)) => Left((
+                AtmiPeekable {
+                    front,
+                    rest: Left(inner),
+                },
+                x,
+            )),
+            Right(term) => Right(term),
+        }
+    }
+}
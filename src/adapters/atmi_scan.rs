@@ -0,0 +1,43 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::scan](crate::AsyncTerminalMoveIteratorExt::scan).
+pub struct AtmiScan<I, S, F>(pub(crate) I, pub(crate) S, pub(crate) F);
+
+impl<I, S, F, Fut, B> AsyncTerminalMoveIterator for AtmiScan<I, S, F>
+where
+    I: AsyncTerminalMoveIterator,
+    S: Send,
+    F: FnMut(S, <I as AsyncTerminalMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = (S, Option<B>)> + Send,
+{
+    type Terminal = (S, <I as AsyncTerminalMoveIterator>::Terminal);
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiScan(mut inner, mut state, mut f) = self;
+        loop {
+            let (next, x) = match AsyncTerminalMoveIterator::into_next(inner).await {
+                Left(next) => next,
+                Right(term) => {
+                    return Right((state, term));
+                }
+            };
+
+            let (s, opty) = f(state, x).await;
+            state = s;
+            match opty {
+                Some(y) => {
+                    return Left((AtmiScan(next, state, f), y));
+                }
+                None => {
+                    inner = next;
+                }
+            }
+        }
+    }
+}
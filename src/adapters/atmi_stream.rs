@@ -0,0 +1,80 @@
+use crate::adapters::TerminalSlot;
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use futures::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type Step<'a, I> = Pin<
+    Box<
+        dyn Future<
+                Output = Either<
+                    (I, <I as AsyncTerminalMoveIterator>::Item),
+                    <I as AsyncTerminalMoveIterator>::Terminal,
+                >,
+            > + Send
+            + 'a,
+    >,
+>;
+
+/// A [Stream] impl from
+/// [AsyncTerminalMoveIteratorExt::into_stream](crate::AsyncTerminalMoveIteratorExt::into_stream).
+///
+/// The `Terminal` is stored in the paired [TerminalSlot] when the stream ends.
+pub struct AtmiStream<'a, I>
+where
+    I: AsyncTerminalMoveIterator,
+{
+    pending: Option<Step<'a, I>>,
+    slot: TerminalSlot<<I as AsyncTerminalMoveIterator>::Terminal>,
+}
+
+impl<'a, I> AtmiStream<'a, I>
+where
+    I: AsyncTerminalMoveIterator + 'a,
+{
+    pub(crate) fn new(
+        inner: I,
+    ) -> (
+        Self,
+        TerminalSlot<<I as AsyncTerminalMoveIterator>::Terminal>,
+    ) {
+        let slot = TerminalSlot::new();
+        let stream = AtmiStream {
+            pending: Some(Box::pin(AsyncTerminalMoveIterator::into_next(inner))),
+            slot: slot.clone(),
+        };
+        (stream, slot)
+    }
+}
+
+impl<'a, I> Stream for AtmiStream<'a, I>
+where
+    I: AsyncTerminalMoveIterator + 'a,
+{
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use Either::*;
+
+        let this = self.get_mut();
+        let pending = match this.pending.as_mut() {
+            Some(pending) => pending,
+            None => return Poll::Ready(None),
+        };
+
+        match pending.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Left((inner, x))) => {
+                this.pending = Some(Box::pin(AsyncTerminalMoveIterator::into_next(inner)));
+                Poll::Ready(Some(x))
+            }
+            Poll::Ready(Right(term)) => {
+                this.pending = None;
+                this.slot.set(term);
+                Poll::Ready(None)
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::then](crate::AsyncTerminalMoveIteratorExt::then).
+pub struct AtmiThen<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> AsyncTerminalMoveIterator for AtmiThen<I, F>
+where
+    I: AsyncTerminalMoveIterator,
+    F: FnMut(<I as AsyncTerminalMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = B> + Send,
+{
+    type Terminal = <I as AsyncTerminalMoveIterator>::Terminal;
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiThen(inner, mut f) = self;
+        let (inner, x) = match AsyncTerminalMoveIterator::into_next(inner).await {
+            Left(next) => next,
+            Right(term) => {
+                return Right(term);
+            }
+        };
+
+        let y = f(x).await;
+        Left((AtmiThen(inner, f), y))
+    }
+}
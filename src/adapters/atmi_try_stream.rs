@@ -0,0 +1,67 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use futures::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type Step<'a, I> = Pin<
+    Box<
+        dyn Future<
+                Output = Either<
+                    (I, <I as AsyncTerminalMoveIterator>::Item),
+                    <I as AsyncTerminalMoveIterator>::Terminal,
+                >,
+            > + Send
+            + 'a,
+    >,
+>;
+
+/// A [Stream] impl from
+/// [AsyncTerminalMoveIteratorExt::into_try_stream](crate::AsyncTerminalMoveIteratorExt::into_try_stream).
+pub struct AtmiTryStream<'a, I>
+where
+    I: AsyncTerminalMoveIterator,
+{
+    pending: Option<Step<'a, I>>,
+}
+
+impl<'a, I> AtmiTryStream<'a, I>
+where
+    I: AsyncTerminalMoveIterator + 'a,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        AtmiTryStream {
+            pending: Some(Box::pin(AsyncTerminalMoveIterator::into_next(inner))),
+        }
+    }
+}
+
+impl<'a, I, E> Stream for AtmiTryStream<'a, I>
+where
+    I: AsyncTerminalMoveIterator<Terminal = Result<(), E>> + 'a,
+{
+    type Item = Result<<I as AsyncTerminalMoveIterator>::Item, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use Either::*;
+
+        let this = self.get_mut();
+        let pending = match this.pending.as_mut() {
+            Some(pending) => pending,
+            None => return Poll::Ready(None),
+        };
+
+        match pending.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Left((inner, x))) => {
+                this.pending = Some(Box::pin(AsyncTerminalMoveIterator::into_next(inner)));
+                Poll::Ready(Some(Ok(x)))
+            }
+            Poll::Ready(Right(term)) => {
+                this.pending = None;
+                Poll::Ready(term.err().map(Err))
+            }
+        }
+    }
+}
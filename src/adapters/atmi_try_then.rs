@@ -0,0 +1,34 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::try_then](crate::AsyncTerminalMoveIteratorExt::try_then).
+pub struct AtmiTryThen<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B, E> AsyncTerminalMoveIterator for AtmiTryThen<I, F>
+where
+    I: AsyncTerminalMoveIterator<Terminal = Result<(), E>>,
+    F: FnMut(<I as AsyncTerminalMoveIterator>::Item) -> Fut + Send,
+    Fut: Future<Output = Result<B, E>> + Send,
+{
+    type Terminal = Result<(), E>;
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiTryThen(inner, mut f) = self;
+        let (inner, x) = match AsyncTerminalMoveIterator::into_next(inner).await {
+            Left(next) => next,
+            Right(term) => {
+                return Right(term);
+            }
+        };
+
+        match f(x).await {
+            Ok(y) => Left((AtmiTryThen(inner, f), y)),
+            Err(e) => Right(Err(e)),
+        }
+    }
+}
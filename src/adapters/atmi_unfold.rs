@@ -0,0 +1,23 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// An [AsyncTerminalMoveIterator] impl from [unfold](crate::asyn::terminal::unfold).
+pub struct AtmiUnfold<S, F>(pub(crate) S, pub(crate) F);
+
+impl<S, F, Fut, B, T> AsyncTerminalMoveIterator for AtmiUnfold<S, F>
+where
+    S: Send,
+    F: FnMut(S) -> Fut + Send,
+    Fut: Future<Output = Either<(S, B), T>> + Send,
+{
+    type Item = B;
+    type Terminal = T;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        let AtmiUnfold(state, mut f) = self;
+        f(state)
+            .await
+            .map_left(|(state, x)| (AtmiUnfold(state, f), x))
+    }
+}
@@ -0,0 +1,80 @@
+use crate::adapters::TimeoutError;
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+type Step<'a, I> = Pin<
+    Box<
+        dyn Future<
+                Output = Either<
+                    (I, <I as AsyncTerminalMoveIterator>::Item),
+                    <I as AsyncTerminalMoveIterator>::Terminal,
+                >,
+            > + Send
+            + 'a,
+    >,
+>;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::with_timeout](crate::AsyncTerminalMoveIteratorExt::with_timeout).
+///
+/// This holds the next step of the underlying iterator, which may already be partially complete
+/// if a previous [AsyncTerminalMoveIterator::into_next] timed out.
+pub struct AtmiWithTimeout<'a, I>
+where
+    I: AsyncTerminalMoveIterator,
+{
+    pending: Step<'a, I>,
+    duration: Duration,
+}
+
+impl<'a, I> AtmiWithTimeout<'a, I>
+where
+    I: AsyncTerminalMoveIterator + 'a,
+{
+    pub(crate) fn new(inner: I, duration: Duration) -> Self {
+        AtmiWithTimeout {
+            pending: Box::pin(AsyncTerminalMoveIterator::into_next(inner)),
+            duration,
+        }
+    }
+
+    /// The timeout applied to each step.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Change the timeout applied to each subsequent step, including any step in progress.
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+}
+
+impl<'a, I, T, E> AsyncTerminalMoveIterator for AtmiWithTimeout<'a, I>
+where
+    I: AsyncTerminalMoveIterator<Terminal = Result<T, E>> + 'a,
+{
+    type Terminal = Result<T, TimeoutError<Self, E>>;
+    type Item = <I as AsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiWithTimeout {
+            mut pending,
+            duration,
+        } = self;
+
+        match tokio::time::timeout(duration, &mut pending).await {
+            Ok(Left((inner, x))) => Left((AtmiWithTimeout::new(inner, duration), x)),
+            Ok(Right(Ok(t))) => Right(Ok(t)),
+            Ok(Right(Err(e))) => Right(Err(TimeoutError::Inner(e))),
+            Err(_) => Right(Err(TimeoutError::TimedOut(AtmiWithTimeout {
+                pending,
+                duration,
+            }))),
+        }
+    }
+}
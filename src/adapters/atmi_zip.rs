@@ -0,0 +1,47 @@
+use crate::adapters::ZipTerminal;
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::zip](crate::AsyncTerminalMoveIteratorExt::zip).
+pub struct AtmiZip<A, B>(pub(crate) A, pub(crate) B);
+
+impl<A, B> AsyncTerminalMoveIterator for AtmiZip<A, B>
+where
+    A: AsyncTerminalMoveIterator,
+    B: AsyncTerminalMoveIterator,
+    <A as AsyncTerminalMoveIterator>::Item: Send,
+{
+    type Terminal = ZipTerminal<
+        A,
+        <A as AsyncTerminalMoveIterator>::Item,
+        <A as AsyncTerminalMoveIterator>::Terminal,
+        B,
+        <B as AsyncTerminalMoveIterator>::Terminal,
+    >;
+    type Item = (
+        <A as AsyncTerminalMoveIterator>::Item,
+        <B as AsyncTerminalMoveIterator>::Item,
+    );
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let AtmiZip(a, b) = self;
+        let (a, xa) = match AsyncTerminalMoveIterator::into_next(a).await {
+            Left(next) => next,
+            Right(terminal) => {
+                return Right(ZipTerminal::LeftEnded { terminal, right: b });
+            }
+        };
+
+        match AsyncTerminalMoveIterator::into_next(b).await {
+            Left((b, xb)) => Left((AtmiZip(a, b), (xa, xb))),
+            Right(terminal) => Right(ZipTerminal::RightEnded {
+                left: a,
+                pending: xa,
+                terminal,
+            }),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::adapters::EitherOrBoth;
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [AsyncTerminalMoveIteratorExt::zip_longest](crate::AsyncTerminalMoveIteratorExt::zip_longest).
+pub struct AtmiZipLongest<A, B>(
+    ZipLongestState<
+        A,
+        <A as AsyncTerminalMoveIterator>::Terminal,
+        B,
+        <B as AsyncTerminalMoveIterator>::Terminal,
+    >,
+)
+where
+    A: AsyncTerminalMoveIterator,
+    B: AsyncTerminalMoveIterator;
+
+enum ZipLongestState<A, TA, B, TB> {
+    Both(A, B),
+    LeftOnly(A, TB),
+    RightOnly(TA, B),
+}
+
+impl<A, B> AtmiZipLongest<A, B>
+where
+    A: AsyncTerminalMoveIterator,
+    B: AsyncTerminalMoveIterator,
+{
+    pub(crate) fn new(a: A, b: B) -> Self {
+        AtmiZipLongest(ZipLongestState::Both(a, b))
+    }
+}
+
+impl<A, B> AsyncTerminalMoveIterator for AtmiZipLongest<A, B>
+where
+    A: AsyncTerminalMoveIterator,
+    B: AsyncTerminalMoveIterator,
+    <A as AsyncTerminalMoveIterator>::Item: Send,
+    <A as AsyncTerminalMoveIterator>::Terminal: Send,
+    <B as AsyncTerminalMoveIterator>::Terminal: Send,
+{
+    type Terminal = (
+        <A as AsyncTerminalMoveIterator>::Terminal,
+        <B as AsyncTerminalMoveIterator>::Terminal,
+    );
+    type Item = EitherOrBoth<
+        <A as AsyncTerminalMoveIterator>::Item,
+        <B as AsyncTerminalMoveIterator>::Item,
+    >;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+        use ZipLongestState::*;
+
+        match self.0 {
+            Both(a, b) => {
+                let nexta = AsyncTerminalMoveIterator::into_next(a).await;
+                match (nexta, AsyncTerminalMoveIterator::into_next(b).await) {
+                    (Left((a, xa)), Left((b, xb))) => {
+                        Left((AtmiZipLongest(Both(a, b)), EitherOrBoth::Both(xa, xb)))
+                    }
+                    (Left((a, xa)), Right(tb)) => {
+                        Left((AtmiZipLongest(LeftOnly(a, tb)), EitherOrBoth::Left(xa)))
+                    }
+                    (Right(ta), Left((b, xb))) => {
+                        Left((AtmiZipLongest(RightOnly(ta, b)), EitherOrBoth::Right(xb)))
+                    }
+                    (Right(ta), Right(tb)) => Right((ta, tb)),
+                }
+            }
+            LeftOnly(a, tb) => match AsyncTerminalMoveIterator::into_next(a).await {
+                Left((a, xa)) => Left((AtmiZipLongest(LeftOnly(a, tb)), EitherOrBoth::Left(xa))),
+                Right(ta) => Right((ta, tb)),
+            },
+            RightOnly(ta, b) => match AsyncTerminalMoveIterator::into_next(b).await {
+                Left((b, xb)) => Left((AtmiZipLongest(RightOnly(ta, b)), EitherOrBoth::Right(xb))),
+                Right(tb) => Right((ta, tb)),
+            },
+        }
+    }
+}
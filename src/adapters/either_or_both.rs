@@ -0,0 +1,12 @@
+/// The `Item` of the `zip_longest` adapters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EitherOrBoth<A, B> {
+    /// Both sides produced an item.
+    Both(A, B),
+
+    /// Only the left side produced an item, because the right side has ended.
+    Left(A),
+
+    /// Only the right side produced an item, because the left side has ended.
+    Right(B),
+}
@@ -0,0 +1,25 @@
+use crate::EndlessMoveIterator;
+
+/// An [EndlessMoveIterator] impl from [EndlessMoveIterator::filter].
+///
+/// If the predicate never accepts another item, [EndlessMoveIterator::into_next] never returns.
+pub struct EmiFilter<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P> EndlessMoveIterator for EmiFilter<I, P>
+where
+    I: EndlessMoveIterator,
+    P: FnMut(&<I as EndlessMoveIterator>::Item) -> bool,
+{
+    type Item = <I as EndlessMoveIterator>::Item;
+
+    fn into_next(self) -> (Self, Self::Item) {
+        let EmiFilter(mut inner, mut pred) = self;
+        loop {
+            let (next, x) = EndlessMoveIterator::into_next(inner);
+            if pred(&x) {
+                return (EmiFilter(next, pred), x);
+            }
+            inner = next;
+        }
+    }
+}
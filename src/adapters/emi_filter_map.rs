@@ -0,0 +1,25 @@
+use crate::EndlessMoveIterator;
+
+/// An [EndlessMoveIterator] impl from [EndlessMoveIterator::filter_map].
+///
+/// If the closure never returns `Some` again, [EndlessMoveIterator::into_next] never returns.
+pub struct EmiFilterMap<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, B> EndlessMoveIterator for EmiFilterMap<I, F>
+where
+    I: EndlessMoveIterator,
+    F: FnMut(<I as EndlessMoveIterator>::Item) -> Option<B>,
+{
+    type Item = B;
+
+    fn into_next(self) -> (Self, Self::Item) {
+        let EmiFilterMap(mut inner, mut f) = self;
+        loop {
+            let (next, x) = EndlessMoveIterator::into_next(inner);
+            if let Some(y) = f(x) {
+                return (EmiFilterMap(next, f), y);
+            }
+            inner = next;
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::EndlessMoveIterator;
+
+/// An [EndlessMoveIterator] impl from [EndlessMoveIterator::inspect].
+pub struct EmiInspect<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F> EndlessMoveIterator for EmiInspect<I, F>
+where
+    I: EndlessMoveIterator,
+    F: FnMut(&<I as EndlessMoveIterator>::Item),
+{
+    type Item = <I as EndlessMoveIterator>::Item;
+
+    fn into_next(self) -> (Self, Self::Item) {
+        let EmiInspect(inner, mut f) = self;
+        let (inner, x) = EndlessMoveIterator::into_next(inner);
+        f(&x);
+        (EmiInspect(inner, f), x)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::EndlessMoveIterator;
+
+/// An [EndlessMoveIterator] impl from [EndlessMoveIterator::map].
+pub struct EmiMap<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, B> EndlessMoveIterator for EmiMap<I, F>
+where
+    I: EndlessMoveIterator,
+    F: FnMut(<I as EndlessMoveIterator>::Item) -> B,
+{
+    type Item = B;
+
+    fn into_next(self) -> (Self, Self::Item) {
+        let EmiMap(inner, mut f) = self;
+        let (inner, x) = EndlessMoveIterator::into_next(inner);
+        let y = f(x);
+        (EmiMap(inner, f), y)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{EndlessMoveIterator, TerminalMoveIterator};
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from [EndlessMoveIterator::map_while].
+pub struct EmiMapWhile<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, B> TerminalMoveIterator for EmiMapWhile<I, F>
+where
+    I: EndlessMoveIterator,
+    F: FnMut(<I as EndlessMoveIterator>::Item) -> Option<B>,
+{
+    type Terminal = I;
+    type Item = B;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let EmiMapWhile(inner, mut f) = self;
+        let (inner, x) = EndlessMoveIterator::into_next(inner);
+        match f(x) {
+            Some(y) => Left((EmiMapWhile(inner, f), y)),
+            None => Right(inner),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::EndlessMoveIterator;
+
+/// An [EndlessMoveIterator] impl from [repeat_with](crate::syn::endless::repeat_with).
+pub struct EmiRepeatWith<F>(pub(crate) F);
+
+impl<F, B> EndlessMoveIterator for EmiRepeatWith<F>
+where
+    F: FnMut() -> B,
+{
+    type Item = B;
+
+    fn into_next(mut self) -> (Self, Self::Item) {
+        let x = (self.0)();
+        (self, x)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{EndlessMoveIterator, TerminalMoveIterator};
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from [EndlessMoveIterator::take].
+pub struct EmiTake<I>(pub(crate) I, pub(crate) usize);
+
+impl<I> TerminalMoveIterator for EmiTake<I>
+where
+    I: EndlessMoveIterator,
+{
+    type Terminal = I;
+    type Item = <I as EndlessMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let EmiTake(inner, n) = self;
+        if n == 0 {
+            Right(inner)
+        } else {
+            let (inner, x) = EndlessMoveIterator::into_next(inner);
+            Left((EmiTake(inner, n - 1), x))
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::EndlessMoveIterator;
+
+/// An [EndlessMoveIterator] impl from [unfold](crate::syn::endless::unfold).
+pub struct EmiUnfold<S, F>(pub(crate) S, pub(crate) F);
+
+impl<S, F, B> EndlessMoveIterator for EmiUnfold<S, F>
+where
+    F: FnMut(S) -> (S, B),
+{
+    type Item = B;
+
+    fn into_next(self) -> (Self, Self::Item) {
+        let EmiUnfold(state, mut f) = self;
+        let (state, x) = f(state);
+        (EmiUnfold(state, f), x)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{EndlessMoveIterator, FiniteMoveIterator};
+
+/// An [EndlessMoveIterator] impl from
+/// [FiniteMoveIteratorExt::into_endless_or](crate::FiniteMoveIteratorExt::into_endless_or).
+pub struct FmiAsEndlessOr<I, F>(pub(crate) Option<I>, pub(crate) F);
+
+impl<I, F> EndlessMoveIterator for FmiAsEndlessOr<I, F>
+where
+    I: FiniteMoveIterator,
+    F: FnMut() -> <I as FiniteMoveIterator>::Item,
+{
+    type Item = <I as FiniteMoveIterator>::Item;
+
+    fn into_next(self) -> (Self, Self::Item) {
+        let FmiAsEndlessOr(inner, mut fallback) = self;
+        if let Some((inner, x)) = inner.and_then(FiniteMoveIterator::into_next) {
+            return (FmiAsEndlessOr(Some(inner), fallback), x);
+        }
+
+        let x = fallback();
+        (FmiAsEndlessOr(None, fallback), x)
+    }
+}
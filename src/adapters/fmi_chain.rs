@@ -0,0 +1,30 @@
+use crate::FiniteMoveIterator;
+use either::Either;
+
+/// A [FiniteMoveIterator] impl from
+/// [FiniteMoveIteratorExt::chain](crate::FiniteMoveIteratorExt::chain).
+pub struct FmiChain<I, J>(pub(crate) Either<(I, J), J>);
+
+impl<I, J> FiniteMoveIterator for FmiChain<I, J>
+where
+    I: FiniteMoveIterator,
+    J: FiniteMoveIterator<Item = <I as FiniteMoveIterator>::Item>,
+{
+    type Item = <I as FiniteMoveIterator>::Item;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        use Either::*;
+
+        let second = match self.0 {
+            Left((first, second)) => match FiniteMoveIterator::into_next(first) {
+                Some((first, x)) => {
+                    return Some((FmiChain(Left((first, second))), x));
+                }
+                None => second,
+            },
+            Right(second) => second,
+        };
+
+        FiniteMoveIterator::into_next(second).map(|(second, x)| (FmiChain(Right(second)), x))
+    }
+}
@@ -0,0 +1,13 @@
+use crate::FiniteMoveIterator;
+use std::marker::PhantomData;
+
+/// A [FiniteMoveIterator] impl from [empty](crate::syn::finite::empty).
+pub struct FmiEmpty<T>(pub(crate) PhantomData<fn() -> T>);
+
+impl<T> FiniteMoveIterator for FmiEmpty<T> {
+    type Item = T;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        None
+    }
+}
@@ -0,0 +1,24 @@
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from
+/// [FiniteMoveIteratorExt::filter](crate::FiniteMoveIteratorExt::filter).
+pub struct FmiFilter<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P> FiniteMoveIterator for FmiFilter<I, P>
+where
+    I: FiniteMoveIterator,
+    P: FnMut(&<I as FiniteMoveIterator>::Item) -> bool,
+{
+    type Item = <I as FiniteMoveIterator>::Item;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        let FmiFilter(mut inner, mut pred) = self;
+        while let Some((next, x)) = FiniteMoveIterator::into_next(inner) {
+            if pred(&x) {
+                return Some((FmiFilter(next, pred), x));
+            }
+            inner = next;
+        }
+        None
+    }
+}
@@ -0,0 +1,24 @@
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from
+/// [FiniteMoveIteratorExt::filter_map](crate::FiniteMoveIteratorExt::filter_map).
+pub struct FmiFilterMap<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, B> FiniteMoveIterator for FmiFilterMap<I, F>
+where
+    I: FiniteMoveIterator,
+    F: FnMut(<I as FiniteMoveIterator>::Item) -> Option<B>,
+{
+    type Item = B;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        let FmiFilterMap(mut inner, mut f) = self;
+        while let Some((next, x)) = FiniteMoveIterator::into_next(inner) {
+            if let Some(y) = f(x) {
+                return Some((FmiFilterMap(next, f), y));
+            }
+            inner = next;
+        }
+        None
+    }
+}
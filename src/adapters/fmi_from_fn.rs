@@ -0,0 +1,16 @@
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from [from_fn](crate::syn::finite::from_fn).
+pub struct FmiFromFn<F>(pub(crate) F);
+
+impl<F, B> FiniteMoveIterator for FmiFromFn<F>
+where
+    F: FnMut() -> Option<B>,
+{
+    type Item = B;
+
+    fn into_next(mut self) -> Option<(Self, Self::Item)> {
+        let x = (self.0)()?;
+        Some((self, x))
+    }
+}
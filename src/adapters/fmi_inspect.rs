@@ -0,0 +1,21 @@
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from
+/// [FiniteMoveIteratorExt::inspect](crate::FiniteMoveIteratorExt::inspect).
+pub struct FmiInspect<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F> FiniteMoveIterator for FmiInspect<I, F>
+where
+    I: FiniteMoveIterator,
+    F: FnMut(&<I as FiniteMoveIterator>::Item),
+{
+    type Item = <I as FiniteMoveIterator>::Item;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        let FmiInspect(inner, mut f) = self;
+        FiniteMoveIterator::into_next(inner).map(|(inner, x)| {
+            f(&x);
+            (FmiInspect(inner, f), x)
+        })
+    }
+}
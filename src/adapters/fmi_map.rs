@@ -0,0 +1,21 @@
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from
+/// [FiniteMoveIteratorExt::map](crate::FiniteMoveIteratorExt::map).
+pub struct FmiMap<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, B> FiniteMoveIterator for FmiMap<I, F>
+where
+    I: FiniteMoveIterator,
+    F: FnMut(<I as FiniteMoveIterator>::Item) -> B,
+{
+    type Item = B;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        let FmiMap(inner, mut f) = self;
+        FiniteMoveIterator::into_next(inner).map(|(inner, x)| {
+            let y = f(x);
+            (FmiMap(inner, f), y)
+        })
+    }
+}
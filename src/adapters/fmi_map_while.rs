@@ -0,0 +1,19 @@
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from
+/// [FiniteMoveIteratorExt::map_while](crate::FiniteMoveIteratorExt::map_while).
+pub struct FmiMapWhile<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, B> FiniteMoveIterator for FmiMapWhile<I, F>
+where
+    I: FiniteMoveIterator,
+    F: FnMut(<I as FiniteMoveIterator>::Item) -> Option<B>,
+{
+    type Item = B;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        let FmiMapWhile(inner, mut f) = self;
+        FiniteMoveIterator::into_next(inner)
+            .and_then(|(inner, x)| f(x).map(|y| (FmiMapWhile(inner, f), y)))
+    }
+}
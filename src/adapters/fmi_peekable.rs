@@ -0,0 +1,74 @@
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from
+/// [FiniteMoveIteratorExt::peekable](crate::FiniteMoveIteratorExt::peekable) which supports
+/// lookahead and push-back.
+pub struct FmiPeekable<I>
+where
+    I: FiniteMoveIterator,
+{
+    // Pushed back or peeked items, with the next item last:
+    front: Vec<<I as FiniteMoveIterator>::Item>,
+    // This is `None` once `I` has ended:
+    rest: Option<I>,
+}
+
+impl<I> FmiPeekable<I>
+where
+    I: FiniteMoveIterator,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        FmiPeekable {
+            front: vec![],
+            rest: Some(inner),
+        }
+    }
+
+    /// Return a reference to the next `Item` without consuming it, or `None` at the end.
+    pub fn peek(&mut self) -> Option<&<I as FiniteMoveIterator>::Item> {
+        if self.front.is_empty() {
+            if let Some((inner, x)) = self.rest.take().and_then(FiniteMoveIterator::into_next) {
+                self.rest = Some(inner);
+                self.front.push(x);
+            }
+        }
+
+        self.front.last()
+    }
+
+    /// Look ahead at the next `Item` without consuming it, moving `self`, which matches the async
+    /// [AfmiPeekable::into_peek](crate::adapters::AfmiPeekable::into_peek). The lookahead is then
+    /// available from [FmiPeekable::peeked].
+    pub fn into_peek(mut self) -> Self {
+        self.peek();
+        self
+    }
+
+    /// Return a reference to the next `Item` if it is known. After [FmiPeekable::peek] or
+    /// [FmiPeekable::into_peek], `None` means the end.
+    pub fn peeked(&self) -> Option<&<I as FiniteMoveIterator>::Item> {
+        self.front.last()
+    }
+
+    /// Put `item` in front, so that it is the next `Item` produced.
+    pub fn push_back(&mut self, item: <I as FiniteMoveIterator>::Item) {
+        self.front.push(item);
+    }
+}
+
+impl<I> FiniteMoveIterator for FmiPeekable<I>
+where
+    I: FiniteMoveIterator,
+{
+    type Item = <I as FiniteMoveIterator>::Item;
+
+    fn into_next(mut self) -> Option<(Self, Self::Item)> {
+        if let Some(x) = self.front.pop() {
+            return Some((self, x));
+        }
+
+        let (inner, x) = FiniteMoveIterator::into_next(self.rest.take()?)?;
+        self.rest = Some(inner);
+        Some((self, x))
+    }
+}
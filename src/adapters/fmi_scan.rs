@@ -0,0 +1,28 @@
+use crate::{FiniteMoveIterator, TerminalMoveIterator};
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [FiniteMoveIteratorExt::scan](crate::FiniteMoveIteratorExt::scan).
+pub struct FmiScan<I, S, F>(pub(crate) I, pub(crate) S, pub(crate) F);
+
+impl<I, S, F, B> TerminalMoveIterator for FmiScan<I, S, F>
+where
+    I: FiniteMoveIterator,
+    F: FnMut(&mut S, <I as FiniteMoveIterator>::Item) -> Option<B>,
+{
+    type Terminal = S;
+    type Item = B;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let FmiScan(mut inner, mut state, mut f) = self;
+        while let Some((next, x)) = FiniteMoveIterator::into_next(inner) {
+            if let Some(y) = f(&mut state, x) {
+                return Left((FmiScan(next, state, f), y));
+            }
+            inner = next;
+        }
+        Right(state)
+    }
+}
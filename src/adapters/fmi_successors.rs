@@ -0,0 +1,18 @@
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from [successors](crate::syn::finite::successors).
+pub struct FmiSuccessors<T, F>(pub(crate) Option<T>, pub(crate) F);
+
+impl<T, F> FiniteMoveIterator for FmiSuccessors<T, F>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    type Item = T;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        let FmiSuccessors(next, mut succ) = self;
+        let x = next?;
+        let next = succ(&x);
+        Some((FmiSuccessors(next, succ), x))
+    }
+}
@@ -0,0 +1,17 @@
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from [unfold](crate::syn::finite::unfold).
+pub struct FmiUnfold<S, F>(pub(crate) S, pub(crate) F);
+
+impl<S, F, B> FiniteMoveIterator for FmiUnfold<S, F>
+where
+    F: FnMut(S) -> Option<(S, B)>,
+{
+    type Item = B;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        let FmiUnfold(state, mut f) = self;
+        let (state, x) = f(state)?;
+        Some((FmiUnfold(state, f), x))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::adapters::ZipTerminal;
+use crate::{FiniteMoveIterator, TerminalMoveIterator};
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [FiniteMoveIteratorExt::zip](crate::FiniteMoveIteratorExt::zip).
+pub struct FmiZip<A, B>(pub(crate) A, pub(crate) B);
+
+impl<A, B> TerminalMoveIterator for FmiZip<A, B>
+where
+    A: FiniteMoveIterator,
+    B: FiniteMoveIterator,
+{
+    type Terminal = ZipTerminal<A, <A as FiniteMoveIterator>::Item, (), B, ()>;
+    type Item = (
+        <A as FiniteMoveIterator>::Item,
+        <B as FiniteMoveIterator>::Item,
+    );
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let FmiZip(a, b) = self;
+        match FiniteMoveIterator::into_next(a) {
+            Some((a, xa)) => match FiniteMoveIterator::into_next(b) {
+                Some((b, xb)) => Left((FmiZip(a, b), (xa, xb))),
+                None => Right(ZipTerminal::RightEnded {
+                    left: a,
+                    pending: xa,
+                    terminal: (),
+                }),
+            },
+            None => Right(ZipTerminal::LeftEnded {
+                terminal: (),
+                right: b,
+            }),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::adapters::EitherOrBoth;
+use crate::FiniteMoveIterator;
+
+/// A [FiniteMoveIterator] impl from
+/// [FiniteMoveIteratorExt::zip_longest](crate::FiniteMoveIteratorExt::zip_longest).
+pub struct FmiZipLongest<A, B>(ZipLongestState<A, B>);
+
+enum ZipLongestState<A, B> {
+    Both(A, B),
+    LeftOnly(A),
+    RightOnly(B),
+}
+
+impl<A, B> FmiZipLongest<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        FmiZipLongest(ZipLongestState::Both(a, b))
+    }
+}
+
+impl<A, B> FiniteMoveIterator for FmiZipLongest<A, B>
+where
+    A: FiniteMoveIterator,
+    B: FiniteMoveIterator,
+{
+    type Item = EitherOrBoth<<A as FiniteMoveIterator>::Item, <B as FiniteMoveIterator>::Item>;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        use ZipLongestState::*;
+
+        match self.0 {
+            Both(a, b) => match (
+                FiniteMoveIterator::into_next(a),
+                FiniteMoveIterator::into_next(b),
+            ) {
+                (Some((a, xa)), Some((b, xb))) => {
+                    Some((FmiZipLongest(Both(a, b)), EitherOrBoth::Both(xa, xb)))
+                }
+                (Some((a, xa)), None) => Some((FmiZipLongest(LeftOnly(a)), EitherOrBoth::Left(xa))),
+                (None, Some((b, xb))) => {
+                    Some((FmiZipLongest(RightOnly(b)), EitherOrBoth::Right(xb)))
+                }
+                (None, None) => None,
+            },
+            LeftOnly(a) => FiniteMoveIterator::into_next(a)
+                .map(|(a, xa)| (FmiZipLongest(LeftOnly(a)), EitherOrBoth::Left(xa))),
+            RightOnly(b) => FiniteMoveIterator::into_next(b)
+                .map(|(b, xb)| (FmiZipLongest(RightOnly(b)), EitherOrBoth::Right(xb))),
+        }
+    }
+}
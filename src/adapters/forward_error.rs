@@ -0,0 +1,10 @@
+/// The error from forwarding an async move iterator into a [Sink](futures::Sink), as with
+/// [AsyncTerminalMoveIteratorExt::forward](crate::AsyncTerminalMoveIteratorExt::forward).
+#[derive(Debug)]
+pub enum ForwardError<I, T, E> {
+    /// The sink rejected an `Item`; the remainder of the iterator is handed back.
+    Send(I, E),
+
+    /// The iterator ended with this `Terminal`, but closing the sink failed.
+    Close(T, E),
+}
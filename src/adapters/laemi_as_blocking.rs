@@ -0,0 +1,19 @@
+use crate::blocking::BlockOn;
+use crate::{EndlessMoveIterator, LocalAsyncEndlessMoveIterator};
+
+/// A [EndlessMoveIterator] impl from [LocalAsyncEndlessMoveIterator::into_blocking] or [LocalAsyncEndlessMoveIterator::into_blocking_with].
+pub struct LaemiAsBlocking<I, B>(pub(crate) I, pub(crate) B);
+
+impl<I, B> EndlessMoveIterator for LaemiAsBlocking<I, B>
+where
+    I: LocalAsyncEndlessMoveIterator,
+    B: BlockOn,
+{
+    type Item = <I as LocalAsyncEndlessMoveIterator>::Item;
+
+    fn into_next(self) -> (Self, Self::Item) {
+        let LaemiAsBlocking(inner, executor) = self;
+        let (inner, x) = executor.block_on(LocalAsyncEndlessMoveIterator::into_next(inner));
+        (LaemiAsBlocking(inner, executor), x)
+    }
+}
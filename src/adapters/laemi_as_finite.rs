@@ -0,0 +1,16 @@
+use crate::{LocalAsyncEndlessMoveIterator, LocalAsyncFiniteMoveIterator};
+
+/// A [LocalAsyncFiniteMoveIterator] impl from [LocalAsyncEndlessMoveIterator::into_async_finite_move_iterator].
+pub struct LaemiAsFinite<I>(pub(crate) I);
+
+impl<I> LocalAsyncFiniteMoveIterator for LaemiAsFinite<I>
+where
+    I: LocalAsyncEndlessMoveIterator,
+{
+    type Item = <I as LocalAsyncEndlessMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let (inner, x) = LocalAsyncEndlessMoveIterator::into_next(self.0).await;
+        Some((LaemiAsFinite(inner), x))
+    }
+}
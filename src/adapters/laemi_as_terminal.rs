@@ -0,0 +1,18 @@
+use crate::{LocalAsyncEndlessMoveIterator, LocalAsyncTerminalMoveIterator};
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from [LocalAsyncEndlessMoveIterator::into_async_terminal_move_iterator].
+pub struct LaemiAsTerminal<I>(pub(crate) I);
+
+impl<I> LocalAsyncTerminalMoveIterator for LaemiAsTerminal<I>
+where
+    I: LocalAsyncEndlessMoveIterator,
+{
+    type Terminal = std::convert::Infallible;
+    type Item = <I as LocalAsyncEndlessMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        let (inner, x) = LocalAsyncEndlessMoveIterator::into_next(self.0).await;
+        Either::Left((LaemiAsTerminal(inner), x))
+    }
+}
@@ -0,0 +1,28 @@
+use crate::LocalAsyncEndlessMoveIterator;
+use std::future::Future;
+
+/// A [LocalAsyncEndlessMoveIterator] impl from [LocalAsyncEndlessMoveIterator::filter_async].
+///
+/// If the predicate never accepts another item, [LocalAsyncEndlessMoveIterator::into_next] never
+/// completes.
+pub struct LaemiFilterAsync<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P, Fut> LocalAsyncEndlessMoveIterator for LaemiFilterAsync<I, P>
+where
+    I: LocalAsyncEndlessMoveIterator,
+    P: FnMut(&<I as LocalAsyncEndlessMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    type Item = <I as LocalAsyncEndlessMoveIterator>::Item;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let LaemiFilterAsync(mut inner, mut pred) = self;
+        loop {
+            let (next, x) = LocalAsyncEndlessMoveIterator::into_next(inner).await;
+            if pred(&x).await {
+                return (LaemiFilterAsync(next, pred), x);
+            }
+            inner = next;
+        }
+    }
+}
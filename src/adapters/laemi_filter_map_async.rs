@@ -0,0 +1,28 @@
+use crate::LocalAsyncEndlessMoveIterator;
+use std::future::Future;
+
+/// A [LocalAsyncEndlessMoveIterator] impl from [LocalAsyncEndlessMoveIterator::filter_map_async].
+///
+/// If the closure never returns `Some` again, [LocalAsyncEndlessMoveIterator::into_next] never
+/// completes.
+pub struct LaemiFilterMapAsync<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> LocalAsyncEndlessMoveIterator for LaemiFilterMapAsync<I, F>
+where
+    I: LocalAsyncEndlessMoveIterator,
+    F: FnMut(<I as LocalAsyncEndlessMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = Option<B>>,
+{
+    type Item = B;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let LaemiFilterMapAsync(mut inner, mut f) = self;
+        loop {
+            let (next, x) = LocalAsyncEndlessMoveIterator::into_next(inner).await;
+            if let Some(y) = f(x).await {
+                return (LaemiFilterMapAsync(next, f), y);
+            }
+            inner = next;
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::LocalAsyncEndlessMoveIterator;
+use std::future::Future;
+
+/// A [LocalAsyncEndlessMoveIterator] impl from [LocalAsyncEndlessMoveIterator::then].
+pub struct LaemiThen<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> LocalAsyncEndlessMoveIterator for LaemiThen<I, F>
+where
+    I: LocalAsyncEndlessMoveIterator,
+    F: FnMut(<I as LocalAsyncEndlessMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = B>,
+{
+    type Item = B;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let LaemiThen(inner, mut f) = self;
+        let (inner, x) = LocalAsyncEndlessMoveIterator::into_next(inner).await;
+        let y = f(x).await;
+        (LaemiThen(inner, f), y)
+    }
+}
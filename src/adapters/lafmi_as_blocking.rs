@@ -0,0 +1,22 @@
+use crate::blocking::BlockOn;
+use crate::{FiniteMoveIterator, LocalAsyncFiniteMoveIterator};
+
+/// A [FiniteMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::into_blocking](crate::LocalAsyncFiniteMoveIteratorExt::into_blocking)
+/// or
+/// [LocalAsyncFiniteMoveIteratorExt::into_blocking_with](crate::LocalAsyncFiniteMoveIteratorExt::into_blocking_with).
+pub struct LafmiAsBlocking<I, B>(pub(crate) I, pub(crate) B);
+
+impl<I, B> FiniteMoveIterator for LafmiAsBlocking<I, B>
+where
+    I: LocalAsyncFiniteMoveIterator,
+    B: BlockOn,
+{
+    type Item = <I as LocalAsyncFiniteMoveIterator>::Item;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        let LafmiAsBlocking(inner, executor) = self;
+        let (inner, x) = executor.block_on(LocalAsyncFiniteMoveIterator::into_next(inner))?;
+        Some((LafmiAsBlocking(inner, executor), x))
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{LocalAsyncEndlessMoveIterator, LocalAsyncFiniteMoveIterator};
+
+/// A [LocalAsyncEndlessMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::into_endless_or](crate::LocalAsyncFiniteMoveIteratorExt::into_endless_or).
+pub struct LafmiAsEndlessOr<I, F>(pub(crate) Option<I>, pub(crate) F);
+
+impl<I, F> LocalAsyncEndlessMoveIterator for LafmiAsEndlessOr<I, F>
+where
+    I: LocalAsyncFiniteMoveIterator,
+    F: FnMut() -> <I as LocalAsyncFiniteMoveIterator>::Item,
+{
+    type Item = <I as LocalAsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        let LafmiAsEndlessOr(inner, mut fallback) = self;
+        if let Some(inner) = inner {
+            if let Some((inner, x)) = LocalAsyncFiniteMoveIterator::into_next(inner).await {
+                return (LafmiAsEndlessOr(Some(inner), fallback), x);
+            }
+        }
+
+        let x = fallback();
+        (LafmiAsEndlessOr(None, fallback), x)
+    }
+}
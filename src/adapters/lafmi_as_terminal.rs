@@ -0,0 +1,22 @@
+use crate::{LocalAsyncFiniteMoveIterator, LocalAsyncTerminalMoveIterator};
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from [LocalAsyncFiniteMoveIterator::into_async_terminal_move_iterator].
+pub struct LafmiAsTerminal<I>(pub(crate) I);
+
+impl<I> LocalAsyncTerminalMoveIterator for LafmiAsTerminal<I>
+where
+    I: LocalAsyncFiniteMoveIterator,
+{
+    type Terminal = ();
+    type Item = <I as LocalAsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::{Left, Right};
+
+        LocalAsyncFiniteMoveIterator::into_next(self.0)
+            .await
+            .map(|(inner, x)| Left((LafmiAsTerminal(inner), x)))
+            .unwrap_or(Right(()))
+    }
+}
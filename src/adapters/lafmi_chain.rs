@@ -0,0 +1,32 @@
+use crate::LocalAsyncFiniteMoveIterator;
+use either::Either;
+
+/// A [LocalAsyncFiniteMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::chain](crate::LocalAsyncFiniteMoveIteratorExt::chain).
+pub struct LafmiChain<I, J>(pub(crate) Either<(I, J), J>);
+
+impl<I, J> LocalAsyncFiniteMoveIterator for LafmiChain<I, J>
+where
+    I: LocalAsyncFiniteMoveIterator,
+    J: LocalAsyncFiniteMoveIterator<Item = <I as LocalAsyncFiniteMoveIterator>::Item>,
+{
+    type Item = <I as LocalAsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        use Either::*;
+
+        let second = match self.0 {
+            Left((first, second)) => match LocalAsyncFiniteMoveIterator::into_next(first).await {
+                Some((first, x)) => {
+                    return Some((LafmiChain(Left((first, second))), x));
+                }
+                None => second,
+            },
+            Right(second) => second,
+        };
+
+        LocalAsyncFiniteMoveIterator::into_next(second)
+            .await
+            .map(|(second, x)| (LafmiChain(Right(second)), x))
+    }
+}
@@ -0,0 +1,26 @@
+use crate::LocalAsyncFiniteMoveIterator;
+use std::future::Future;
+
+/// A [LocalAsyncFiniteMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::filter_async](crate::LocalAsyncFiniteMoveIteratorExt::filter_async).
+pub struct LafmiFilterAsync<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P, Fut> LocalAsyncFiniteMoveIterator for LafmiFilterAsync<I, P>
+where
+    I: LocalAsyncFiniteMoveIterator,
+    P: FnMut(&<I as LocalAsyncFiniteMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    type Item = <I as LocalAsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let LafmiFilterAsync(mut inner, mut pred) = self;
+        loop {
+            let (next, x) = LocalAsyncFiniteMoveIterator::into_next(inner).await?;
+            if pred(&x).await {
+                return Some((LafmiFilterAsync(next, pred), x));
+            }
+            inner = next;
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::LocalAsyncFiniteMoveIterator;
+use std::future::Future;
+
+/// A [LocalAsyncFiniteMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::filter_map_async](crate::LocalAsyncFiniteMoveIteratorExt::filter_map_async).
+pub struct LafmiFilterMapAsync<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> LocalAsyncFiniteMoveIterator for LafmiFilterMapAsync<I, F>
+where
+    I: LocalAsyncFiniteMoveIterator,
+    F: FnMut(<I as LocalAsyncFiniteMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = Option<B>>,
+{
+    type Item = B;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let LafmiFilterMapAsync(mut inner, mut f) = self;
+        loop {
+            let (next, x) = LocalAsyncFiniteMoveIterator::into_next(inner).await?;
+            if let Some(y) = f(x).await {
+                return Some((LafmiFilterMapAsync(next, f), y));
+            }
+            inner = next;
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::LocalAsyncFiniteMoveIterator;
+
+/// A [LocalAsyncFiniteMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::peekable](crate::LocalAsyncFiniteMoveIteratorExt::peekable)
+/// which supports lookahead and push-back.
+pub struct LafmiPeekable<I>
+where
+    I: LocalAsyncFiniteMoveIterator,
+{
+    // Pushed back or peeked items, with the next item last:
+    front: Vec<<I as LocalAsyncFiniteMoveIterator>::Item>,
+    // This is `None` once `I` has ended:
+    rest: Option<I>,
+}
+
+impl<I> LafmiPeekable<I>
+where
+    I: LocalAsyncFiniteMoveIterator,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        LafmiPeekable {
+            front: vec![],
+            rest: Some(inner),
+        }
+    }
+
+    /// Look ahead at the next `Item` without consuming it, which is then available from
+    /// [LafmiPeekable::peeked].
+    ///
+    /// This moves `self`, as [LocalAsyncFiniteMoveIterator::into_next] does, so dropping the returned
+    /// future drops the iterator rather than leaving it partially advanced.
+    pub async fn into_peek(self) -> Self {
+        let LafmiPeekable { mut front, rest } = self;
+        let rest = match rest {
+            Some(inner) if front.is_empty() => LocalAsyncFiniteMoveIterator::into_next(inner)
+                .await
+                .map(|(inner, x)| {
+                    front.push(x);
+                    inner
+                }),
+            other => other,
+        };
+
+        LafmiPeekable { front, rest }
+    }
+
+    /// Return a reference to the next `Item` if it is known. After [LafmiPeekable::into_peek],
+    /// `None` means the end.
+    pub fn peeked(&self) -> Option<&<I as LocalAsyncFiniteMoveIterator>::Item> {
+        self.front.last()
+    }
+
+    /// Put `item` in front, so that it is the next `Item` produced.
+    pub fn push_back(&mut self, item: <I as LocalAsyncFiniteMoveIterator>::Item) {
+        self.front.push(item);
+    }
+}
+
+impl<I> LocalAsyncFiniteMoveIterator for LafmiPeekable<I>
+where
+    I: LocalAsyncFiniteMoveIterator,
+{
+    type Item = <I as LocalAsyncFiniteMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let LafmiPeekable { mut front, rest } = self;
+        if let Some(x) = front.pop() {
+            return Some((LafmiPeekable { front, rest }, x));
+        }
+
+        let (inner, x) = LocalAsyncFiniteMoveIterator::into_next(rest?).await?;
+        Some((
+            LafmiPeekable {
+                front,
+                rest: Some(inner),
+            },
+            x,
+        ))
+    }
+}
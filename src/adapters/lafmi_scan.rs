@@ -0,0 +1,42 @@
+use crate::{LocalAsyncFiniteMoveIterator, LocalAsyncTerminalMoveIterator};
+use either::Either;
+use std::future::Future;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::scan](crate::LocalAsyncFiniteMoveIteratorExt::scan).
+pub struct LafmiScan<I, S, F>(pub(crate) I, pub(crate) S, pub(crate) F);
+
+impl<I, S, F, Fut, B> LocalAsyncTerminalMoveIterator for LafmiScan<I, S, F>
+where
+    I: LocalAsyncFiniteMoveIterator,
+    F: FnMut(S, <I as LocalAsyncFiniteMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = (S, Option<B>)>,
+{
+    type Terminal = S;
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LafmiScan(mut inner, mut state, mut f) = self;
+        loop {
+            let (next, x) = match LocalAsyncFiniteMoveIterator::into_next(inner).await {
+                Some(next) => next,
+                None => {
+                    return Right(state);
+                }
+            };
+
+            let (s, opty) = f(state, x).await;
+            state = s;
+            match opty {
+                Some(y) => {
+                    return Left((LafmiScan(next, state, f), y));
+                }
+                None => {
+                    inner = next;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::LocalAsyncFiniteMoveIterator;
+use std::future::Future;
+
+/// A [LocalAsyncFiniteMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::then](crate::LocalAsyncFiniteMoveIteratorExt::then).
+pub struct LafmiThen<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> LocalAsyncFiniteMoveIterator for LafmiThen<I, F>
+where
+    I: LocalAsyncFiniteMoveIterator,
+    F: FnMut(<I as LocalAsyncFiniteMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = B>,
+{
+    type Item = B;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let LafmiThen(inner, mut f) = self;
+        let (inner, x) = LocalAsyncFiniteMoveIterator::into_next(inner).await?;
+        let y = f(x).await;
+        Some((LafmiThen(inner, f), y))
+    }
+}
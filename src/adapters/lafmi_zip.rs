@@ -0,0 +1,43 @@
+use crate::adapters::ZipTerminal;
+use crate::{LocalAsyncFiniteMoveIterator, LocalAsyncTerminalMoveIterator};
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::zip](crate::LocalAsyncFiniteMoveIteratorExt::zip).
+pub struct LafmiZip<A, B>(pub(crate) A, pub(crate) B);
+
+impl<A, B> LocalAsyncTerminalMoveIterator for LafmiZip<A, B>
+where
+    A: LocalAsyncFiniteMoveIterator,
+    B: LocalAsyncFiniteMoveIterator,
+{
+    type Terminal = ZipTerminal<A, <A as LocalAsyncFiniteMoveIterator>::Item, (), B, ()>;
+    type Item = (
+        <A as LocalAsyncFiniteMoveIterator>::Item,
+        <B as LocalAsyncFiniteMoveIterator>::Item,
+    );
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LafmiZip(a, b) = self;
+        let (a, xa) = match LocalAsyncFiniteMoveIterator::into_next(a).await {
+            Some(next) => next,
+            None => {
+                return Right(ZipTerminal::LeftEnded {
+                    terminal: (),
+                    right: b,
+                });
+            }
+        };
+
+        match LocalAsyncFiniteMoveIterator::into_next(b).await {
+            Some((b, xb)) => Left((LafmiZip(a, b), (xa, xb))),
+            None => Right(ZipTerminal::RightEnded {
+                left: a,
+                pending: xa,
+                terminal: (),
+            }),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::adapters::EitherOrBoth;
+use crate::LocalAsyncFiniteMoveIterator;
+
+/// A [LocalAsyncFiniteMoveIterator] impl from
+/// [LocalAsyncFiniteMoveIteratorExt::zip_longest](crate::LocalAsyncFiniteMoveIteratorExt::zip_longest).
+pub struct LafmiZipLongest<A, B>(ZipLongestState<A, B>);
+
+enum ZipLongestState<A, B> {
+    Both(A, B),
+    LeftOnly(A),
+    RightOnly(B),
+}
+
+impl<A, B> LafmiZipLongest<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        LafmiZipLongest(ZipLongestState::Both(a, b))
+    }
+}
+
+impl<A, B> LocalAsyncFiniteMoveIterator for LafmiZipLongest<A, B>
+where
+    A: LocalAsyncFiniteMoveIterator,
+    B: LocalAsyncFiniteMoveIterator,
+{
+    type Item = EitherOrBoth<
+        <A as LocalAsyncFiniteMoveIterator>::Item,
+        <B as LocalAsyncFiniteMoveIterator>::Item,
+    >;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        use ZipLongestState::*;
+
+        match self.0 {
+            Both(a, b) => {
+                let nexta = LocalAsyncFiniteMoveIterator::into_next(a).await;
+                match (nexta, LocalAsyncFiniteMoveIterator::into_next(b).await) {
+                    (Some((a, xa)), Some((b, xb))) => {
+                        Some((LafmiZipLongest(Both(a, b)), EitherOrBoth::Both(xa, xb)))
+                    }
+                    (Some((a, xa)), None) => {
+                        Some((LafmiZipLongest(LeftOnly(a)), EitherOrBoth::Left(xa)))
+                    }
+                    (None, Some((b, xb))) => {
+                        Some((LafmiZipLongest(RightOnly(b)), EitherOrBoth::Right(xb)))
+                    }
+                    (None, None) => None,
+                }
+            }
+            LeftOnly(a) => LocalAsyncFiniteMoveIterator::into_next(a)
+                .await
+                .map(|(a, xa)| (LafmiZipLongest(LeftOnly(a)), EitherOrBoth::Left(xa))),
+            RightOnly(b) => LocalAsyncFiniteMoveIterator::into_next(b)
+                .await
+                .map(|(b, xb)| (LafmiZipLongest(RightOnly(b)), EitherOrBoth::Right(xb))),
+        }
+    }
+}
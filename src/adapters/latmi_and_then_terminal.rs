@@ -0,0 +1,36 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::and_then_terminal](crate::LocalAsyncTerminalMoveIteratorExt::and_then_terminal).
+pub struct LatmiAndThenTerminal<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, R> LocalAsyncTerminalMoveIterator for LatmiAndThenTerminal<I, F>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    F: FnMut(<I as LocalAsyncTerminalMoveIterator>::Terminal) -> Either<I, R>,
+{
+    type Terminal = R;
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiAndThenTerminal(mut inner, mut f) = self;
+        loop {
+            match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+                Left((next, x)) => {
+                    return Left((LatmiAndThenTerminal(next, f), x));
+                }
+                Right(term) => match f(term) {
+                    Left(next) => {
+                        inner = next;
+                    }
+                    Right(r) => {
+                        return Right(r);
+                    }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::blocking::BlockOn;
+use crate::{LocalAsyncTerminalMoveIterator, TerminalMoveIterator};
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::into_blocking](crate::LocalAsyncTerminalMoveIteratorExt::into_blocking)
+/// or
+/// [LocalAsyncTerminalMoveIteratorExt::into_blocking_with](crate::LocalAsyncTerminalMoveIteratorExt::into_blocking_with).
+pub struct LatmiAsBlocking<I, B>(pub(crate) I, pub(crate) B);
+
+impl<I, B> TerminalMoveIterator for LatmiAsBlocking<I, B>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    B: BlockOn,
+{
+    type Terminal = <I as LocalAsyncTerminalMoveIterator>::Terminal;
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiAsBlocking(inner, executor) = self;
+        match executor.block_on(LocalAsyncTerminalMoveIterator::into_next(inner)) {
+            Left((inner, x)) => Left((LatmiAsBlocking(inner, executor), x)),
+            Right(term) => Right(term),
+        }
+    }
+}
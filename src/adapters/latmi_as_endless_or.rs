@@ -0,0 +1,37 @@
+use crate::{LocalAsyncEndlessMoveIterator, LocalAsyncTerminalMoveIterator};
+use either::Either;
+
+/// A [LocalAsyncEndlessMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::into_endless_or](crate::LocalAsyncTerminalMoveIteratorExt::into_endless_or).
+pub struct LatmiAsEndlessOr<I, F>(
+    pub(crate) Either<I, <I as LocalAsyncTerminalMoveIterator>::Terminal>,
+    pub(crate) F,
+)
+where
+    I: LocalAsyncTerminalMoveIterator;
+
+impl<I, F> LocalAsyncEndlessMoveIterator for LatmiAsEndlessOr<I, F>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    F: FnMut(
+        &<I as LocalAsyncTerminalMoveIterator>::Terminal,
+    ) -> <I as LocalAsyncTerminalMoveIterator>::Item,
+{
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> (Self, Self::Item) {
+        use Either::*;
+
+        let LatmiAsEndlessOr(state, mut fallback) = self;
+        let state = match state {
+            Left(inner) => match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+                Left((inner, x)) => return (LatmiAsEndlessOr(Left(inner), fallback), x),
+                Right(term) => term,
+            },
+            Right(term) => term,
+        };
+
+        let x = fallback(&state);
+        (LatmiAsEndlessOr(Right(state), fallback), x)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{LocalAsyncFiniteMoveIterator, LocalAsyncTerminalMoveIterator};
+
+/// A [LocalAsyncFiniteMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::into_finite_discarding_terminal](crate::LocalAsyncTerminalMoveIteratorExt::into_finite_discarding_terminal).
+pub struct LatmiAsFiniteDiscarding<I>(pub(crate) I);
+
+impl<I> LocalAsyncFiniteMoveIterator for LatmiAsFiniteDiscarding<I>
+where
+    I: LocalAsyncTerminalMoveIterator,
+{
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        LocalAsyncTerminalMoveIterator::into_next(self.0)
+            .await
+            .left()
+            .map(|(inner, x)| (LatmiAsFiniteDiscarding(inner), x))
+    }
+}
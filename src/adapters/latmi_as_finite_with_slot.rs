@@ -0,0 +1,32 @@
+use crate::adapters::TerminalSlot;
+use crate::{LocalAsyncFiniteMoveIterator, LocalAsyncTerminalMoveIterator};
+use either::Either;
+
+/// A [LocalAsyncFiniteMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::into_finite_with_terminal_slot](crate::LocalAsyncTerminalMoveIteratorExt::into_finite_with_terminal_slot).
+pub struct LatmiAsFiniteWithSlot<I>(
+    pub(crate) I,
+    pub(crate) TerminalSlot<<I as LocalAsyncTerminalMoveIterator>::Terminal>,
+)
+where
+    I: LocalAsyncTerminalMoveIterator;
+
+impl<I> LocalAsyncFiniteMoveIterator for LatmiAsFiniteWithSlot<I>
+where
+    I: LocalAsyncTerminalMoveIterator,
+{
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        use Either::*;
+
+        let LatmiAsFiniteWithSlot(inner, slot) = self;
+        match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+            Left((inner, x)) => Some((LatmiAsFiniteWithSlot(inner, slot), x)),
+            Right(term) => {
+                slot.set(term);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::futpool::FuturePool;
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+
+// A step of the source, boxed so that it can stay in flight between calls to `into_next`:
+type Step<'a, I> = Pin<
+    Box<
+        dyn Future<
+                Output = Either<
+                    (I, <I as LocalAsyncTerminalMoveIterator>::Item),
+                    <I as LocalAsyncTerminalMoveIterator>::Terminal,
+                >,
+            > + 'a,
+    >,
+>;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::buffered](crate::LocalAsyncTerminalMoveIteratorExt::buffered).
+pub struct LatmiBuffered<'a, I>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    <I as LocalAsyncTerminalMoveIterator>::Item: Future,
+{
+    // The source step in flight, or the `Terminal` once the source has ended:
+    rest: Either<Step<'a, I>, <I as LocalAsyncTerminalMoveIterator>::Terminal>,
+    pool: FuturePool<<I as LocalAsyncTerminalMoveIterator>::Item>,
+    limit: usize,
+}
+
+impl<'a, I> LatmiBuffered<'a, I>
+where
+    I: LocalAsyncTerminalMoveIterator + 'a,
+    <I as LocalAsyncTerminalMoveIterator>::Item: Future,
+{
+    pub(crate) fn new(inner: I, limit: usize) -> Self {
+        assert!(limit > 0, "buffered requires a limit of at least 1");
+        LatmiBuffered {
+            rest: Either::Left(Box::pin(LocalAsyncTerminalMoveIterator::into_next(inner))),
+            pool: FuturePool::new(),
+            limit,
+        }
+    }
+}
+
+impl<'a, I> LocalAsyncTerminalMoveIterator for LatmiBuffered<'a, I>
+where
+    I: LocalAsyncTerminalMoveIterator + 'a,
+    <I as LocalAsyncTerminalMoveIterator>::Item: Future,
+{
+    type Terminal = <I as LocalAsyncTerminalMoveIterator>::Terminal;
+    type Item = <<I as LocalAsyncTerminalMoveIterator>::Item as Future>::Output;
+
+    async fn into_next(mut self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        // Race the source against the pool, so that finished futures are produced even while the
+        // source is pending:
+        let next = poll_fn(|cx| {
+            while self.pool.len() < self.limit {
+                let step = match &mut self.rest {
+                    Left(step) => step,
+                    Right(_) => break,
+                };
+                match step.as_mut().poll(cx) {
+                    Poll::Ready(Left((inner, fut))) => {
+                        self.pool.push(fut);
+                        self.rest =
+                            Left(Box::pin(LocalAsyncTerminalMoveIterator::into_next(inner)));
+                    }
+                    Poll::Ready(Right(term)) => self.rest = Right(term),
+                    Poll::Pending => break,
+                }
+            }
+
+            match self.pool.poll_next_ordered(cx) {
+                Poll::Ready(None) if self.rest.is_left() => Poll::Pending,
+                other => other,
+            }
+        })
+        .await;
+
+        match next {
+            Some(x) => Left((self, x)),
+            None => match self.rest {
+                Right(term) => Right(term),
+                Left(_) => unreachable!("the pool only empties after the source terminates"),
+            },
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::continue_with](crate::LocalAsyncTerminalMoveIteratorExt::continue_with).
+pub struct LatmiContinueWith<I, F, J>(pub(crate) Either<(I, F), J>);
+
+impl<I, F, J> LocalAsyncTerminalMoveIterator for LatmiContinueWith<I, F, J>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    F: FnOnce(<I as LocalAsyncTerminalMoveIterator>::Terminal) -> J,
+    J: LocalAsyncTerminalMoveIterator<Item = <I as LocalAsyncTerminalMoveIterator>::Item>,
+{
+    type Terminal = <J as LocalAsyncTerminalMoveIterator>::Terminal;
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let second = match self.0 {
+            Left((first, f)) => match LocalAsyncTerminalMoveIterator::into_next(first).await {
+                Left((first, x)) => {
+                    return Left((LatmiContinueWith(Left((first, f))), x));
+                }
+                Right(term) => f(term),
+            },
+            Right(second) => second,
+        };
+
+        match LocalAsyncTerminalMoveIterator::into_next(second).await {
+            Left((second, x)) => Left((LatmiContinueWith(Right(second)), x)),
+            Right(term) => Right(term),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::filter_async](crate::LocalAsyncTerminalMoveIteratorExt::filter_async).
+pub struct LatmiFilterAsync<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P, Fut> LocalAsyncTerminalMoveIterator for LatmiFilterAsync<I, P>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    P: FnMut(&<I as LocalAsyncTerminalMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    type Terminal = <I as LocalAsyncTerminalMoveIterator>::Terminal;
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiFilterAsync(mut inner, mut pred) = self;
+        loop {
+            let (next, x) = match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+                Left(next) => next,
+                Right(term) => {
+                    return Right(term);
+                }
+            };
+
+            if pred(&x).await {
+                return Left((LatmiFilterAsync(next, pred), x));
+            }
+            inner = next;
+        }
+    }
+}
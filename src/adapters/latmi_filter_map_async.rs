@@ -0,0 +1,36 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::filter_map_async](crate::LocalAsyncTerminalMoveIteratorExt::filter_map_async).
+pub struct LatmiFilterMapAsync<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> LocalAsyncTerminalMoveIterator for LatmiFilterMapAsync<I, F>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    F: FnMut(<I as LocalAsyncTerminalMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = Option<B>>,
+{
+    type Terminal = <I as LocalAsyncTerminalMoveIterator>::Terminal;
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiFilterMapAsync(mut inner, mut f) = self;
+        loop {
+            let (next, x) = match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+                Left(next) => next,
+                Right(term) => {
+                    return Right(term);
+                }
+            };
+
+            if let Some(y) = f(x).await {
+                return Left((LatmiFilterMapAsync(next, f), y));
+            }
+            inner = next;
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::map_err](crate::LocalAsyncTerminalMoveIteratorExt::map_err)
+/// or
+/// [LocalAsyncTerminalMoveIteratorExt::err_into](crate::LocalAsyncTerminalMoveIteratorExt::err_into).
+pub struct LatmiMapErr<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, T, E, E2> LocalAsyncTerminalMoveIterator for LatmiMapErr<I, F>
+where
+    I: LocalAsyncTerminalMoveIterator<Terminal = Result<T, E>>,
+    F: FnOnce(E) -> E2,
+{
+    type Terminal = Result<T, E2>;
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiMapErr(inner, f) = self;
+        match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+            Left((inner, x)) => Left((LatmiMapErr(inner, f), x)),
+            Right(term) => Right(term.map_err(f)),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::map_terminal](crate::LocalAsyncTerminalMoveIteratorExt::map_terminal).
+pub struct LatmiMapTerminal<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, R> LocalAsyncTerminalMoveIterator for LatmiMapTerminal<I, F>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    F: FnOnce(<I as LocalAsyncTerminalMoveIterator>::Terminal) -> R,
+{
+    type Terminal = R;
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiMapTerminal(inner, f) = self;
+        match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+            Left((inner, x)) => Left((LatmiMapTerminal(inner, f), x)),
+            Right(term) => Right(f(term)),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::peekable](crate::LocalAsyncTerminalMoveIteratorExt::peekable)
+/// which supports lookahead and push-back.
+pub struct LatmiPeekable<I>
+where
+    I: LocalAsyncTerminalMoveIterator,
+{
+    // Pushed back or peeked items, with the next item last:
+    front: Vec<<I as LocalAsyncTerminalMoveIterator>::Item>,
+    rest: Either<I, <I as LocalAsyncTerminalMoveIterator>::Terminal>,
+}
+
+impl<I> LatmiPeekable<I>
+where
+    I: LocalAsyncTerminalMoveIterator,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        LatmiPeekable {
+            front: vec![],
+            rest: Either::Left(inner),
+        }
+    }
+
+    /// Look ahead at the next `Item` or the `Terminal` without consuming it, which is then
+    /// available from [LatmiPeekable::peeked].
+    ///
+    /// This moves `self`, as [LocalAsyncTerminalMoveIterator::into_next] does, so dropping the
+    /// returned future drops the iterator rather than leaving it partially advanced.
+    pub async fn into_peek(self) -> Self {
+        use Either::*;
+
+        let LatmiPeekable { mut front, rest } = self;
+        let rest = match rest {
+            Left(inner) if front.is_empty() => {
+                match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+                    Left((inner, x)) => {
+                        front.push(x);
+                        Left(inner)
+                    }
+                    Right(term) => Right(term),
+                }
+            }
+            other => other,
+        };
+
+        LatmiPeekable { front, rest }
+    }
+
+    /// Return a reference to either the next `Item` or the `Terminal` if it is known, which it
+    /// always is after [LatmiPeekable::into_peek] or [LatmiPeekable::push_back].
+    pub fn peeked(
+        &self,
+    ) -> Option<
+        Either<
+            &<I as LocalAsyncTerminalMoveIterator>::Item,
+            &<I as LocalAsyncTerminalMoveIterator>::Terminal,
+        >,
+    > {
+        use Either::*;
+
+        match (self.front.last(), &self.rest) {
+            (Some(x), _) => Some(Left(x)),
+            (None, Right(term)) => Some(Right(term)),
+            (None, Left(_)) => None,
+        }
+    }
+
+    /// Put `item` in front, so that it is the next `Item` produced.
+    pub fn push_back(&mut self, item: <I as LocalAsyncTerminalMoveIterator>::Item) {
+        self.front.push(item);
+    }
+}
+
+impl<I> LocalAsyncTerminalMoveIterator for LatmiPeekable<I>
+where
+    I: LocalAsyncTerminalMoveIterator,
+{
+    type Terminal = <I as LocalAsyncTerminalMoveIterator>::Terminal;
+    type Item = <I as LocalAsyncTerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiPeekable { mut front, rest } = self;
+        if let Some(x) = front.pop() {
+            return Left((LatmiPeekable { front, rest }, x));
+        }
+
+        let inner = match rest {
+            Left(inner) => inner,
+            Right(term) => {
+                return Right(term);
+            }
+        };
+
+        match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+            Left((inner, x)) => Left((
+                LatmiPeekable {
+                    front,
+                    rest: Left(inner),
+                },
+                x,
+            )),
+            Right(term) => Right(term),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::scan](crate::LocalAsyncTerminalMoveIteratorExt::scan).
+pub struct LatmiScan<I, S, F>(pub(crate) I, pub(crate) S, pub(crate) F);
+
+impl<I, S, F, Fut, B> LocalAsyncTerminalMoveIterator for LatmiScan<I, S, F>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    F: FnMut(S, <I as LocalAsyncTerminalMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = (S, Option<B>)>,
+{
+    type Terminal = (S, <I as LocalAsyncTerminalMoveIterator>::Terminal);
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiScan(mut inner, mut state, mut f) = self;
+        loop {
+            let (next, x) = match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+                Left(next) => next,
+                Right(term) => {
+                    return Right((state, term));
+                }
+            };
+
+            let (s, opty) = f(state, x).await;
+            state = s;
+            match opty {
+                Some(y) => {
+                    return Left((LatmiScan(next, state, f), y));
+                }
+                None => {
+                    inner = next;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::then](crate::LocalAsyncTerminalMoveIteratorExt::then).
+pub struct LatmiThen<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B> LocalAsyncTerminalMoveIterator for LatmiThen<I, F>
+where
+    I: LocalAsyncTerminalMoveIterator,
+    F: FnMut(<I as LocalAsyncTerminalMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = B>,
+{
+    type Terminal = <I as LocalAsyncTerminalMoveIterator>::Terminal;
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiThen(inner, mut f) = self;
+        let (inner, x) = match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+            Left(next) => next,
+            Right(term) => {
+                return Right(term);
+            }
+        };
+
+        let y = f(x).await;
+        Left((LatmiThen(inner, f), y))
+    }
+}
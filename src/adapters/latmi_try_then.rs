@@ -0,0 +1,34 @@
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+use std::future::Future;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::try_then](crate::LocalAsyncTerminalMoveIteratorExt::try_then).
+pub struct LatmiTryThen<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, Fut, B, E> LocalAsyncTerminalMoveIterator for LatmiTryThen<I, F>
+where
+    I: LocalAsyncTerminalMoveIterator<Terminal = Result<(), E>>,
+    F: FnMut(<I as LocalAsyncTerminalMoveIterator>::Item) -> Fut,
+    Fut: Future<Output = Result<B, E>>,
+{
+    type Terminal = Result<(), E>;
+    type Item = B;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiTryThen(inner, mut f) = self;
+        let (inner, x) = match LocalAsyncTerminalMoveIterator::into_next(inner).await {
+            Left(next) => next,
+            Right(term) => {
+                return Right(term);
+            }
+        };
+
+        match f(x).await {
+            Ok(y) => Left((LatmiTryThen(inner, f), y)),
+            Err(e) => Right(Err(e)),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::adapters::ZipTerminal;
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::zip](crate::LocalAsyncTerminalMoveIteratorExt::zip).
+pub struct LatmiZip<A, B>(pub(crate) A, pub(crate) B);
+
+impl<A, B> LocalAsyncTerminalMoveIterator for LatmiZip<A, B>
+where
+    A: LocalAsyncTerminalMoveIterator,
+    B: LocalAsyncTerminalMoveIterator,
+{
+    type Terminal = ZipTerminal<
+        A,
+        <A as LocalAsyncTerminalMoveIterator>::Item,
+        <A as LocalAsyncTerminalMoveIterator>::Terminal,
+        B,
+        <B as LocalAsyncTerminalMoveIterator>::Terminal,
+    >;
+    type Item = (
+        <A as LocalAsyncTerminalMoveIterator>::Item,
+        <B as LocalAsyncTerminalMoveIterator>::Item,
+    );
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let LatmiZip(a, b) = self;
+        let (a, xa) = match LocalAsyncTerminalMoveIterator::into_next(a).await {
+            Left(next) => next,
+            Right(terminal) => {
+                return Right(ZipTerminal::LeftEnded { terminal, right: b });
+            }
+        };
+
+        match LocalAsyncTerminalMoveIterator::into_next(b).await {
+            Left((b, xb)) => Left((LatmiZip(a, b), (xa, xb))),
+            Right(terminal) => Right(ZipTerminal::RightEnded {
+                left: a,
+                pending: xa,
+                terminal,
+            }),
+        }
+    }
+}
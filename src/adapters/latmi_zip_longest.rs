@@ -0,0 +1,79 @@
+use crate::adapters::EitherOrBoth;
+use crate::LocalAsyncTerminalMoveIterator;
+use either::Either;
+
+/// A [LocalAsyncTerminalMoveIterator] impl from
+/// [LocalAsyncTerminalMoveIteratorExt::zip_longest](crate::LocalAsyncTerminalMoveIteratorExt::zip_longest).
+pub struct LatmiZipLongest<A, B>(
+    ZipLongestState<
+        A,
+        <A as LocalAsyncTerminalMoveIterator>::Terminal,
+        B,
+        <B as LocalAsyncTerminalMoveIterator>::Terminal,
+    >,
+)
+where
+    A: LocalAsyncTerminalMoveIterator,
+    B: LocalAsyncTerminalMoveIterator;
+
+enum ZipLongestState<A, TA, B, TB> {
+    Both(A, B),
+    LeftOnly(A, TB),
+    RightOnly(TA, B),
+}
+
+impl<A, B> LatmiZipLongest<A, B>
+where
+    A: LocalAsyncTerminalMoveIterator,
+    B: LocalAsyncTerminalMoveIterator,
+{
+    pub(crate) fn new(a: A, b: B) -> Self {
+        LatmiZipLongest(ZipLongestState::Both(a, b))
+    }
+}
+
+impl<A, B> LocalAsyncTerminalMoveIterator for LatmiZipLongest<A, B>
+where
+    A: LocalAsyncTerminalMoveIterator,
+    B: LocalAsyncTerminalMoveIterator,
+{
+    type Terminal = (
+        <A as LocalAsyncTerminalMoveIterator>::Terminal,
+        <B as LocalAsyncTerminalMoveIterator>::Terminal,
+    );
+    type Item = EitherOrBoth<
+        <A as LocalAsyncTerminalMoveIterator>::Item,
+        <B as LocalAsyncTerminalMoveIterator>::Item,
+    >;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+        use ZipLongestState::*;
+
+        match self.0 {
+            Both(a, b) => {
+                let nexta = LocalAsyncTerminalMoveIterator::into_next(a).await;
+                match (nexta, LocalAsyncTerminalMoveIterator::into_next(b).await) {
+                    (Left((a, xa)), Left((b, xb))) => {
+                        Left((LatmiZipLongest(Both(a, b)), EitherOrBoth::Both(xa, xb)))
+                    }
+                    (Left((a, xa)), Right(tb)) => {
+                        Left((LatmiZipLongest(LeftOnly(a, tb)), EitherOrBoth::Left(xa)))
+                    }
+                    (Right(ta), Left((b, xb))) => {
+                        Left((LatmiZipLongest(RightOnly(ta, b)), EitherOrBoth::Right(xb)))
+                    }
+                    (Right(ta), Right(tb)) => Right((ta, tb)),
+                }
+            }
+            LeftOnly(a, tb) => match LocalAsyncTerminalMoveIterator::into_next(a).await {
+                Left((a, xa)) => Left((LatmiZipLongest(LeftOnly(a, tb)), EitherOrBoth::Left(xa))),
+                Right(ta) => Right((ta, tb)),
+            },
+            RightOnly(ta, b) => match LocalAsyncTerminalMoveIterator::into_next(b).await {
+                Left((b, xb)) => Left((LatmiZipLongest(RightOnly(ta, b)), EitherOrBoth::Right(xb))),
+                Right(tb) => Right((ta, tb)),
+            },
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::AsyncFiniteMoveIterator;
+use futures::{Stream, StreamExt};
+
+/// An [AsyncFiniteMoveIterator] impl from
+/// [async_finite_move_iterator_from_stream](crate::async_finite_move_iterator_from_stream).
+pub struct StreamAsAfmi<S>(pub(crate) S);
+
+impl<S> AsyncFiniteMoveIterator for StreamAsAfmi<S>
+where
+    S: Stream + Unpin + Send,
+{
+    type Item = <S as Stream>::Item;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let StreamAsAfmi(mut stream) = self;
+        let x = stream.next().await?;
+        Some((StreamAsAfmi(stream), x))
+    }
+}
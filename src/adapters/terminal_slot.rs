@@ -0,0 +1,28 @@
+use std::sync::{Arc, Mutex};
+
+/// A shared handle which receives the `Terminal` of an iterator once it ends, from conversions such
+/// as
+/// [TerminalMoveIteratorExt::into_finite_with_terminal_slot](crate::TerminalMoveIteratorExt::into_finite_with_terminal_slot).
+pub struct TerminalSlot<T>(Arc<Mutex<Option<T>>>);
+
+impl<T> TerminalSlot<T> {
+    pub(crate) fn new() -> Self {
+        TerminalSlot(Arc::new(Mutex::new(None)))
+    }
+
+    /// Take the `Terminal`, which is `None` until iteration has ended, or if it was already
+    /// taken.
+    pub fn take(&self) -> Option<T> {
+        self.0.lock().unwrap().take()
+    }
+
+    pub(crate) fn set(&self, term: T) {
+        *self.0.lock().unwrap() = Some(term);
+    }
+}
+
+impl<T> Clone for TerminalSlot<T> {
+    fn clone(&self) -> Self {
+        TerminalSlot(self.0.clone())
+    }
+}
@@ -0,0 +1,11 @@
+/// The error half of the `Terminal` from
+/// [AsyncTerminalMoveIteratorExt::with_timeout](crate::AsyncTerminalMoveIteratorExt::with_timeout).
+#[derive(Debug)]
+pub enum TimeoutError<I, E> {
+    /// The underlying iterator terminated with this error.
+    Inner(E),
+
+    /// A single step took longer than the timeout. The iterator is handed back with that step
+    /// still in progress, so it can be resumed without losing an `Item`.
+    TimedOut(I),
+}
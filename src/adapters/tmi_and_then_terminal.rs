@@ -0,0 +1,36 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::and_then_terminal](crate::TerminalMoveIteratorExt::and_then_terminal).
+pub struct TmiAndThenTerminal<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, R> TerminalMoveIterator for TmiAndThenTerminal<I, F>
+where
+    I: TerminalMoveIterator,
+    F: FnMut(<I as TerminalMoveIterator>::Terminal) -> Either<I, R>,
+{
+    type Terminal = R;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiAndThenTerminal(mut inner, mut f) = self;
+        loop {
+            match TerminalMoveIterator::into_next(inner) {
+                Left((next, x)) => {
+                    return Left((TmiAndThenTerminal(next, f), x));
+                }
+                Right(term) => match f(term) {
+                    Left(next) => {
+                        inner = next;
+                    }
+                    Right(r) => {
+                        return Right(r);
+                    }
+                },
+            }
+        }
+    }
+}
@@ -1,11 +1,9 @@
-use crate::{AsyncTerminalMoveIterator, TerminalMoveIterator};
-use async_trait::async_trait;
+use crate::{AsyncTerminalMoveIterator, LocalAsyncTerminalMoveIterator, TerminalMoveIterator};
 use either::Either;
 
-/// An [AsyncTerminalMoveIterator] impl from [TerminalMoveIterator::into_async].
+/// An [AsyncTerminalMoveIterator] and [LocalAsyncTerminalMoveIterator] impl from [TerminalMoveIterator::into_async].
 pub struct TmiAsAsync<I>(pub(crate) I);
 
-#[async_trait]
 impl<I> AsyncTerminalMoveIterator for TmiAsAsync<I>
 where
     I: TerminalMoveIterator + Sync + Send,
@@ -13,7 +11,24 @@ where
     type Terminal = <I as TerminalMoveIterator>::Terminal;
     type Item = <I as TerminalMoveIterator>::Item;
 
-    async fn into_next(mut self) -> Either<(Self, Self::Item), Self::Terminal> {
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        match TerminalMoveIterator::into_next(self.0) {
+            Left((inner, x)) => Left((TmiAsAsync(inner), x)),
+            Right(term) => Right(term),
+        }
+    }
+}
+
+impl<I> LocalAsyncTerminalMoveIterator for TmiAsAsync<I>
+where
+    I: TerminalMoveIterator,
+{
+    type Terminal = <I as TerminalMoveIterator>::Terminal;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
         use Either::*;
 
         match TerminalMoveIterator::into_next(self.0) {
@@ -0,0 +1,35 @@
+use crate::{EndlessMoveIterator, TerminalMoveIterator};
+use either::Either;
+
+/// An [EndlessMoveIterator] impl from
+/// [TerminalMoveIteratorExt::into_endless_or](crate::TerminalMoveIteratorExt::into_endless_or).
+pub struct TmiAsEndlessOr<I, F>(
+    pub(crate) Either<I, <I as TerminalMoveIterator>::Terminal>,
+    pub(crate) F,
+)
+where
+    I: TerminalMoveIterator;
+
+impl<I, F> EndlessMoveIterator for TmiAsEndlessOr<I, F>
+where
+    I: TerminalMoveIterator,
+    F: FnMut(&<I as TerminalMoveIterator>::Terminal) -> <I as TerminalMoveIterator>::Item,
+{
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> (Self, Self::Item) {
+        use Either::*;
+
+        let TmiAsEndlessOr(state, mut fallback) = self;
+        let state = match state {
+            Left(inner) => match TerminalMoveIterator::into_next(inner) {
+                Left((inner, x)) => return (TmiAsEndlessOr(Left(inner), fallback), x),
+                Right(term) => term,
+            },
+            Right(term) => term,
+        };
+
+        let x = fallback(&state);
+        (TmiAsEndlessOr(Right(state), fallback), x)
+    }
+}
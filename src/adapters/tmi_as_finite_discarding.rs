@@ -0,0 +1,18 @@
+use crate::{FiniteMoveIterator, TerminalMoveIterator};
+
+/// A [FiniteMoveIterator] impl from
+/// [TerminalMoveIteratorExt::into_finite_discarding_terminal](crate::TerminalMoveIteratorExt::into_finite_discarding_terminal).
+pub struct TmiAsFiniteDiscarding<I>(pub(crate) I);
+
+impl<I> FiniteMoveIterator for TmiAsFiniteDiscarding<I>
+where
+    I: TerminalMoveIterator,
+{
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        TerminalMoveIterator::into_next(self.0)
+            .left()
+            .map(|(inner, x)| (TmiAsFiniteDiscarding(inner), x))
+    }
+}
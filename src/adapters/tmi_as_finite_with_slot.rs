@@ -0,0 +1,32 @@
+use crate::adapters::TerminalSlot;
+use crate::{FiniteMoveIterator, TerminalMoveIterator};
+use either::Either;
+
+/// A [FiniteMoveIterator] impl from
+/// [TerminalMoveIteratorExt::into_finite_with_terminal_slot](crate::TerminalMoveIteratorExt::into_finite_with_terminal_slot).
+pub struct TmiAsFiniteWithSlot<I>(
+    pub(crate) I,
+    pub(crate) TerminalSlot<<I as TerminalMoveIterator>::Terminal>,
+)
+where
+    I: TerminalMoveIterator;
+
+impl<I> FiniteMoveIterator for TmiAsFiniteWithSlot<I>
+where
+    I: TerminalMoveIterator,
+{
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Option<(Self, Self::Item)> {
+        use Either::*;
+
+        let TmiAsFiniteWithSlot(inner, slot) = self;
+        match TerminalMoveIterator::into_next(inner) {
+            Left((inner, x)) => Some((TmiAsFiniteWithSlot(inner, slot), x)),
+            Right(term) => {
+                slot.set(term);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+use std::iter::FusedIterator;
+
+/// An [Iterator] impl from [TerminalMoveIterator::into_iter], which keeps the `Terminal` once
+/// exhausted so it can be retrieved with [TmiAsIterator::into_terminal].
+pub struct TmiAsIterator<I>(Option<Either<I, <I as TerminalMoveIterator>::Terminal>>)
+where
+    I: TerminalMoveIterator;
+
+impl<I> TmiAsIterator<I>
+where
+    I: TerminalMoveIterator,
+{
+    pub(crate) fn new(x: I) -> Self {
+        TmiAsIterator(Some(Either::Left(x)))
+    }
+
+    /// Retrieve the `Terminal` if iteration has ended, otherwise give back `self` to continue.
+    pub fn into_terminal(self) -> Result<I::Terminal, Self> {
+        match self.0 {
+            Some(Either::Right(term)) => Ok(term),
+            state => Err(TmiAsIterator(state)),
+        }
+    }
+}
+
+impl<I> Iterator for TmiAsIterator<I>
+where
+    I: TerminalMoveIterator,
+{
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use Either::*;
+
+        match self.0.take()? {
+            Left(inner) => match inner.into_next() {
+                Left((inner, x)) => {
+                    self.0 = Some(Left(inner));
+                    Some(x)
+                }
+                Right(term) => {
+                    self.0 = Some(Right(term));
+                    None
+                }
+            },
+            Right(term) => {
+                self.0 = Some(Right(term));
+                None
+            }
+        }
+    }
+}
+
+impl<I> FusedIterator for TmiAsIterator<I> where I: TerminalMoveIterator {}
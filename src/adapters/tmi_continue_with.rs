@@ -0,0 +1,35 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::continue_with](crate::TerminalMoveIteratorExt::continue_with).
+pub struct TmiContinueWith<I, F, J>(pub(crate) Either<(I, F), J>);
+
+impl<I, F, J> TerminalMoveIterator for TmiContinueWith<I, F, J>
+where
+    I: TerminalMoveIterator,
+    F: FnOnce(<I as TerminalMoveIterator>::Terminal) -> J,
+    J: TerminalMoveIterator<Item = <I as TerminalMoveIterator>::Item>,
+{
+    type Terminal = <J as TerminalMoveIterator>::Terminal;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let second = match self.0 {
+            Left((first, f)) => match TerminalMoveIterator::into_next(first) {
+                Left((first, x)) => {
+                    return Left((TmiContinueWith(Left((first, f))), x));
+                }
+                Right(term) => f(term),
+            },
+            Right(second) => second,
+        };
+
+        match TerminalMoveIterator::into_next(second) {
+            Left((second, x)) => Left((TmiContinueWith(Right(second)), x)),
+            Right(term) => Right(term),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::filter](crate::TerminalMoveIteratorExt::filter).
+pub struct TmiFilter<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P> TerminalMoveIterator for TmiFilter<I, P>
+where
+    I: TerminalMoveIterator,
+    P: FnMut(&<I as TerminalMoveIterator>::Item) -> bool,
+{
+    type Terminal = <I as TerminalMoveIterator>::Terminal;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiFilter(mut inner, mut pred) = self;
+        loop {
+            match TerminalMoveIterator::into_next(inner) {
+                Left((next, x)) => {
+                    if pred(&x) {
+                        return Left((TmiFilter(next, pred), x));
+                    }
+                    inner = next;
+                }
+                Right(term) => {
+                    return Right(term);
+                }
+            }
+        }
+    }
+}
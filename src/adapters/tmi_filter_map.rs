@@ -0,0 +1,34 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::filter_map](crate::TerminalMoveIteratorExt::filter_map).
+pub struct TmiFilterMap<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, B> TerminalMoveIterator for TmiFilterMap<I, F>
+where
+    I: TerminalMoveIterator,
+    F: FnMut(<I as TerminalMoveIterator>::Item) -> Option<B>,
+{
+    type Terminal = <I as TerminalMoveIterator>::Terminal;
+    type Item = B;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiFilterMap(mut inner, mut f) = self;
+        loop {
+            match TerminalMoveIterator::into_next(inner) {
+                Left((next, x)) => {
+                    if let Some(y) = f(x) {
+                        return Left((TmiFilterMap(next, f), y));
+                    }
+                    inner = next;
+                }
+                Right(term) => {
+                    return Right(term);
+                }
+            }
+        }
+    }
+}
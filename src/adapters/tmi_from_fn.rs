@@ -0,0 +1,17 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from [from_fn](crate::syn::terminal::from_fn).
+pub struct TmiFromFn<F>(pub(crate) F);
+
+impl<F, B, T> TerminalMoveIterator for TmiFromFn<F>
+where
+    F: FnMut() -> Either<B, T>,
+{
+    type Item = B;
+    type Terminal = T;
+
+    fn into_next(mut self) -> Either<(Self, Self::Item), Self::Terminal> {
+        (self.0)().map_left(|x| (self, x))
+    }
+}
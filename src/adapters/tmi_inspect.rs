@@ -0,0 +1,28 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::inspect](crate::TerminalMoveIteratorExt::inspect).
+pub struct TmiInspect<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F> TerminalMoveIterator for TmiInspect<I, F>
+where
+    I: TerminalMoveIterator,
+    F: FnMut(&<I as TerminalMoveIterator>::Item),
+{
+    type Terminal = <I as TerminalMoveIterator>::Terminal;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiInspect(inner, mut f) = self;
+        match TerminalMoveIterator::into_next(inner) {
+            Left((inner, x)) => {
+                f(&x);
+                Left((TmiInspect(inner, f), x))
+            }
+            Right(term) => Right(term),
+        }
+    }
+}
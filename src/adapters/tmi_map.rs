@@ -0,0 +1,28 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::map](crate::TerminalMoveIteratorExt::map).
+pub struct TmiMap<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, B> TerminalMoveIterator for TmiMap<I, F>
+where
+    I: TerminalMoveIterator,
+    F: FnMut(<I as TerminalMoveIterator>::Item) -> B,
+{
+    type Terminal = <I as TerminalMoveIterator>::Terminal;
+    type Item = B;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiMap(inner, mut f) = self;
+        match TerminalMoveIterator::into_next(inner) {
+            Left((inner, x)) => {
+                let y = f(x);
+                Left((TmiMap(inner, f), y))
+            }
+            Right(term) => Right(term),
+        }
+    }
+}
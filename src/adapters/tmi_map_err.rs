@@ -0,0 +1,26 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::map_err](crate::TerminalMoveIteratorExt::map_err) or
+/// [TerminalMoveIteratorExt::err_into](crate::TerminalMoveIteratorExt::err_into).
+pub struct TmiMapErr<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, T, E, E2> TerminalMoveIterator for TmiMapErr<I, F>
+where
+    I: TerminalMoveIterator<Terminal = Result<T, E>>,
+    F: FnOnce(E) -> E2,
+{
+    type Terminal = Result<T, E2>;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiMapErr(inner, f) = self;
+        match TerminalMoveIterator::into_next(inner) {
+            Left((inner, x)) => Left((TmiMapErr(inner, f), x)),
+            Right(term) => Right(term.map_err(f)),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::map_terminal](crate::TerminalMoveIteratorExt::map_terminal).
+pub struct TmiMapTerminal<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, R> TerminalMoveIterator for TmiMapTerminal<I, F>
+where
+    I: TerminalMoveIterator,
+    F: FnOnce(<I as TerminalMoveIterator>::Terminal) -> R,
+{
+    type Terminal = R;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiMapTerminal(inner, f) = self;
+        match TerminalMoveIterator::into_next(inner) {
+            Left((inner, x)) => Left((TmiMapTerminal(inner, f), x)),
+            Right(term) => Right(f(term)),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::map_while](crate::TerminalMoveIteratorExt::map_while).
+pub struct TmiMapWhile<I, F>(pub(crate) I, pub(crate) F);
+
+impl<I, F, B> TerminalMoveIterator for TmiMapWhile<I, F>
+where
+    I: TerminalMoveIterator,
+    F: FnMut(<I as TerminalMoveIterator>::Item) -> Option<B>,
+{
+    type Terminal = Either<I, <I as TerminalMoveIterator>::Terminal>;
+    type Item = B;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiMapWhile(inner, mut f) = self;
+        match TerminalMoveIterator::into_next(inner) {
+            Left((inner, x)) => match f(x) {
+                Some(y) => Left((TmiMapWhile(inner, f), y)),
+                None => Right(Left(inner)),
+            },
+            Right(term) => Right(Right(term)),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from [once_then](crate::syn::terminal::once_then).
+pub struct TmiOnceThen<I, T>(pub(crate) Option<I>, pub(crate) T);
+
+impl<I, T> TerminalMoveIterator for TmiOnceThen<I, T> {
+    type Item = I;
+    type Terminal = T;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        match self {
+            TmiOnceThen(Some(x), term) => Left((TmiOnceThen(None, term), x)),
+            TmiOnceThen(None, term) => Right(term),
+        }
+    }
+}
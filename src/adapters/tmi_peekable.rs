@@ -0,0 +1,132 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::peekable](crate::TerminalMoveIteratorExt::peekable) which supports
+/// lookahead and push-back.
+pub struct TmiPeekable<I>
+where
+    I: TerminalMoveIterator,
+{
+    // Pushed back or peeked items, with the next item last:
+    front: Vec<<I as TerminalMoveIterator>::Item>,
+    // This is only `None` transiently within `peek`:
+    rest: Option<Either<I, <I as TerminalMoveIterator>::Terminal>>,
+}
+
+impl<I> TmiPeekable<I>
+where
+    I: TerminalMoveIterator,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        TmiPeekable {
+            front: vec![],
+            rest: Some(Either::Left(inner)),
+        }
+    }
+
+    /// Return a reference to either the next `Item` or the `Terminal`, without consuming it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use either::Either::{Left, Right};
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// let mut it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Err("eof")])
+    ///     .peekable();
+    ///
+    /// assert_eq!(it.peek(), Left(&1));
+    ///
+    /// let (mut it, x) = it.into_next().left().unwrap();
+    /// assert_eq!(x, 1);
+    ///
+    /// // Check for the end of input without losing the terminal:
+    /// assert_eq!(it.peek(), Right(&Err("eof")));
+    ///
+    /// // Put `x` back in front:
+    /// it.push_back(x);
+    /// assert_eq!(it.peek(), Left(&1));
+    ///
+    /// let (it, _) = it.into_next().left().unwrap();
+    /// assert_eq!(it.into_next().right(), Some(Err("eof")));
+    /// ```
+    pub fn peek(
+        &mut self,
+    ) -> Either<&<I as TerminalMoveIterator>::Item, &<I as TerminalMoveIterator>::Terminal> {
+        use Either::*;
+
+        let rest = self.rest.take().expect("peekable rest is present");
+        self.rest = Some(match rest {
+            Left(inner) if self.front.is_empty() => match TerminalMoveIterator::into_next(inner) {
+                Left((inner, x)) => {
+                    self.front.push(x);
+                    Left(inner)
+                }
+                Right(term) => Right(term),
+            },
+            other => other,
+        });
+
+        match (self.front.last(), self.rest.as_ref()) {
+            (Some(x), _) => Left(x),
+            (None, Some(Right(term))) => Right(term),
+            (None, _) => unreachable!("an empty front implies a known terminal"),
+        }
+    }
+
+    /// Look ahead at the next `Item` or the `Terminal` without consuming it, moving `self`, which
+    /// matches the async [AtmiPeekable::into_peek](crate::adapters::AtmiPeekable::into_peek).
+    /// The lookahead is then available from [TmiPeekable::peeked].
+    pub fn into_peek(mut self) -> Self {
+        self.peek();
+        self
+    }
+
+    /// Return a reference to either the next `Item` or the `Terminal` if it is known, which it
+    /// always is after [TmiPeekable::peek], [TmiPeekable::into_peek] or [TmiPeekable::push_back].
+    pub fn peeked(
+        &self,
+    ) -> Option<Either<&<I as TerminalMoveIterator>::Item, &<I as TerminalMoveIterator>::Terminal>>
+    {
+        use Either::*;
+
+        match (self.front.last(), self.rest.as_ref()) {
+            (Some(x), _) => Some(Left(x)),
+            (None, Some(Right(term))) => Some(Right(term)),
+            (None, _) => None,
+        }
+    }
+
+    /// Put `item` in front, so that it is the next `Item` produced.
+    pub fn push_back(&mut self, item: <I as TerminalMoveIterator>::Item) {
+        self.front.push(item);
+    }
+}
+
+impl<I> TerminalMoveIterator for TmiPeekable<I>
+where
+    I: TerminalMoveIterator,
+{
+    type Terminal = <I as TerminalMoveIterator>::Terminal;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(mut self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        if let Some(x) = self.front.pop() {
+            return Left((self, x));
+        }
+
+        match self.rest.take().expect("peekable rest is present") {
+            Left(inner) => match TerminalMoveIterator::into_next(inner) {
+                Left((inner, x)) => {
+                    self.rest = Some(Left(inner));
+                    Left((self, x))
+                }
+                Right(term) => Right(term),
+            },
+            Right(term) => Right(term),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::scan](crate::TerminalMoveIteratorExt::scan).
+pub struct TmiScan<I, S, F>(pub(crate) I, pub(crate) S, pub(crate) F);
+
+impl<I, S, F, B> TerminalMoveIterator for TmiScan<I, S, F>
+where
+    I: TerminalMoveIterator,
+    F: FnMut(&mut S, <I as TerminalMoveIterator>::Item) -> Option<B>,
+{
+    type Terminal = (S, <I as TerminalMoveIterator>::Terminal);
+    type Item = B;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiScan(mut inner, mut state, mut f) = self;
+        loop {
+            match TerminalMoveIterator::into_next(inner) {
+                Left((next, x)) => {
+                    if let Some(y) = f(&mut state, x) {
+                        return Left((TmiScan(next, state, f), y));
+                    }
+                    inner = next;
+                }
+                Right(term) => {
+                    return Right((state, term));
+                }
+            }
+        }
+    }
+}
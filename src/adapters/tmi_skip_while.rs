@@ -0,0 +1,35 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::skip_while](crate::TerminalMoveIteratorExt::skip_while).
+pub struct TmiSkipWhile<I, P>(pub(crate) I, pub(crate) Option<P>);
+
+impl<I, P> TerminalMoveIterator for TmiSkipWhile<I, P>
+where
+    I: TerminalMoveIterator,
+    P: FnMut(&<I as TerminalMoveIterator>::Item) -> bool,
+{
+    type Terminal = <I as TerminalMoveIterator>::Terminal;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiSkipWhile(mut inner, mut optpred) = self;
+        loop {
+            match TerminalMoveIterator::into_next(inner) {
+                Left((next, x)) => {
+                    if optpred.as_mut().map(|pred| pred(&x)).unwrap_or(false) {
+                        inner = next;
+                    } else {
+                        return Left((TmiSkipWhile(next, None), x));
+                    }
+                }
+                Right(term) => {
+                    return Right(term);
+                }
+            }
+        }
+    }
+}
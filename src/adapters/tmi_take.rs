@@ -0,0 +1,28 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::take](crate::TerminalMoveIteratorExt::take).
+pub struct TmiTake<I>(pub(crate) I, pub(crate) usize);
+
+impl<I> TerminalMoveIterator for TmiTake<I>
+where
+    I: TerminalMoveIterator,
+{
+    type Terminal = Either<I, <I as TerminalMoveIterator>::Terminal>;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiTake(inner, n) = self;
+        if n == 0 {
+            return Right(Left(inner));
+        }
+
+        match TerminalMoveIterator::into_next(inner) {
+            Left((inner, x)) => Left((TmiTake(inner, n - 1), x)),
+            Right(term) => Right(Right(term)),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::take_while](crate::TerminalMoveIteratorExt::take_while).
+pub struct TmiTakeWhile<I, P>(pub(crate) I, pub(crate) P);
+
+impl<I, P> TerminalMoveIterator for TmiTakeWhile<I, P>
+where
+    I: TerminalMoveIterator,
+    P: FnMut(&<I as TerminalMoveIterator>::Item) -> bool,
+{
+    type Terminal =
+        Either<(I, <I as TerminalMoveIterator>::Item), <I as TerminalMoveIterator>::Terminal>;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiTakeWhile(inner, mut pred) = self;
+        match TerminalMoveIterator::into_next(inner) {
+            Left((inner, x)) => {
+                if pred(&x) {
+                    Left((TmiTakeWhile(inner, pred), x))
+                } else {
+                    Right(Left((inner, x)))
+                }
+            }
+            Right(term) => Right(Right(term)),
+        }
+    }
+}
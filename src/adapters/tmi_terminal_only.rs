@@ -0,0 +1,15 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+use std::marker::PhantomData;
+
+/// A [TerminalMoveIterator] impl from [terminal_only](crate::syn::terminal::terminal_only).
+pub struct TmiTerminalOnly<I, T>(pub(crate) T, pub(crate) PhantomData<fn() -> I>);
+
+impl<I, T> TerminalMoveIterator for TmiTerminalOnly<I, T> {
+    type Item = I;
+    type Terminal = T;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        Either::Right(self.0)
+    }
+}
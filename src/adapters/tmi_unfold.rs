@@ -0,0 +1,18 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from [unfold](crate::syn::terminal::unfold).
+pub struct TmiUnfold<S, F>(pub(crate) S, pub(crate) F);
+
+impl<S, F, B, T> TerminalMoveIterator for TmiUnfold<S, F>
+where
+    F: FnMut(S) -> Either<(S, B), T>,
+{
+    type Item = B;
+    type Terminal = T;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        let TmiUnfold(state, mut f) = self;
+        f(state).map_left(|(state, x)| (TmiUnfold(state, f), x))
+    }
+}
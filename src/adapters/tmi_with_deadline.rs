@@ -0,0 +1,29 @@
+use crate::TerminalMoveIterator;
+use either::Either;
+use std::time::Instant;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::with_deadline](crate::TerminalMoveIteratorExt::with_deadline).
+pub struct TmiWithDeadline<I>(pub(crate) I, pub(crate) Instant);
+
+impl<I> TerminalMoveIterator for TmiWithDeadline<I>
+where
+    I: TerminalMoveIterator,
+{
+    type Terminal = Either<I, <I as TerminalMoveIterator>::Terminal>;
+    type Item = <I as TerminalMoveIterator>::Item;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiWithDeadline(inner, deadline) = self;
+        if Instant::now() >= deadline {
+            return Right(Left(inner));
+        }
+
+        match TerminalMoveIterator::into_next(inner) {
+            Left((inner, x)) => Left((TmiWithDeadline(inner, deadline), x)),
+            Right(term) => Right(Right(term)),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::adapters::ZipTerminal;
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::zip](crate::TerminalMoveIteratorExt::zip).
+pub struct TmiZip<A, B>(pub(crate) A, pub(crate) B);
+
+impl<A, B> TerminalMoveIterator for TmiZip<A, B>
+where
+    A: TerminalMoveIterator,
+    B: TerminalMoveIterator,
+{
+    type Terminal = ZipTerminal<
+        A,
+        <A as TerminalMoveIterator>::Item,
+        <A as TerminalMoveIterator>::Terminal,
+        B,
+        <B as TerminalMoveIterator>::Terminal,
+    >;
+    type Item = (
+        <A as TerminalMoveIterator>::Item,
+        <B as TerminalMoveIterator>::Item,
+    );
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TmiZip(a, b) = self;
+        match TerminalMoveIterator::into_next(a) {
+            Left((a, xa)) => match TerminalMoveIterator::into_next(b) {
+                Left((b, xb)) => Left((TmiZip(a, b), (xa, xb))),
+                Right(terminal) => Right(ZipTerminal::RightEnded {
+                    left: a,
+                    pending: xa,
+                    terminal,
+                }),
+            },
+            Right(terminal) => Right(ZipTerminal::LeftEnded { terminal, right: b }),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::adapters::EitherOrBoth;
+use crate::TerminalMoveIterator;
+use either::Either;
+
+/// A [TerminalMoveIterator] impl from
+/// [TerminalMoveIteratorExt::zip_longest](crate::TerminalMoveIteratorExt::zip_longest).
+pub struct TmiZipLongest<A, B>(
+    ZipLongestState<
+        A,
+        <A as TerminalMoveIterator>::Terminal,
+        B,
+        <B as TerminalMoveIterator>::Terminal,
+    >,
+)
+where
+    A: TerminalMoveIterator,
+    B: TerminalMoveIterator;
+
+enum ZipLongestState<A, TA, B, TB> {
+    Both(A, B),
+    LeftOnly(A, TB),
+    RightOnly(TA, B),
+}
+
+impl<A, B> TmiZipLongest<A, B>
+where
+    A: TerminalMoveIterator,
+    B: TerminalMoveIterator,
+{
+    pub(crate) fn new(a: A, b: B) -> Self {
+        TmiZipLongest(ZipLongestState::Both(a, b))
+    }
+}
+
+impl<A, B> TerminalMoveIterator for TmiZipLongest<A, B>
+where
+    A: TerminalMoveIterator,
+    B: TerminalMoveIterator,
+{
+    type Terminal = (
+        <A as TerminalMoveIterator>::Terminal,
+        <B as TerminalMoveIterator>::Terminal,
+    );
+    type Item = EitherOrBoth<<A as TerminalMoveIterator>::Item, <B as TerminalMoveIterator>::Item>;
+
+    fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+        use ZipLongestState::*;
+
+        match self.0 {
+            Both(a, b) => match (
+                TerminalMoveIterator::into_next(a),
+                TerminalMoveIterator::into_next(b),
+            ) {
+                (Left((a, xa)), Left((b, xb))) => {
+                    Left((TmiZipLongest(Both(a, b)), EitherOrBoth::Both(xa, xb)))
+                }
+                (Left((a, xa)), Right(tb)) => {
+                    Left((TmiZipLongest(LeftOnly(a, tb)), EitherOrBoth::Left(xa)))
+                }
+                (Right(ta), Left((b, xb))) => {
+                    Left((TmiZipLongest(RightOnly(ta, b)), EitherOrBoth::Right(xb)))
+                }
+                (Right(ta), Right(tb)) => Right((ta, tb)),
+            },
+            LeftOnly(a, tb) => match TerminalMoveIterator::into_next(a) {
+                Left((a, xa)) => Left((TmiZipLongest(LeftOnly(a, tb)), EitherOrBoth::Left(xa))),
+                Right(ta) => Right((ta, tb)),
+            },
+            RightOnly(ta, b) => match TerminalMoveIterator::into_next(b) {
+                Left((b, xb)) => Left((TmiZipLongest(RightOnly(ta, b)), EitherOrBoth::Right(xb))),
+                Right(tb) => Right((ta, tb)),
+            },
+        }
+    }
+}
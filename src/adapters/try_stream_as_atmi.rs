@@ -0,0 +1,26 @@
+use crate::AsyncTerminalMoveIterator;
+use either::Either;
+use futures::{Stream, StreamExt};
+
+/// An [AsyncTerminalMoveIterator] impl from
+/// [async_terminal_move_iterator_from_try_stream](crate::async_terminal_move_iterator_from_try_stream).
+pub struct TryStreamAsAtmi<S>(pub(crate) S);
+
+impl<S, T, E> AsyncTerminalMoveIterator for TryStreamAsAtmi<S>
+where
+    S: Stream<Item = Result<T, E>> + Unpin + Send,
+{
+    type Terminal = Result<(), E>;
+    type Item = T;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let TryStreamAsAtmi(mut stream) = self;
+        match stream.next().await {
+            Some(Ok(x)) => Left((TryStreamAsAtmi(stream), x)),
+            Some(Err(e)) => Right(Err(e)),
+            None => Right(Ok(())),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+/// The `Terminal` of the `zip` adapters, which identifies the side which ended first and carries
+/// the unfinished remainder of the other side.
+///
+/// The left side is always advanced first, so if the right side ends first, the `Item` already
+/// produced by the left side is returned as `pending`.
+#[derive(Debug)]
+pub enum ZipTerminal<A, XA, TA, B, TB> {
+    /// The left side ended first with `terminal`, and the `right` side was not advanced.
+    LeftEnded { terminal: TA, right: B },
+
+    /// The right side ended with `terminal` after the `left` side produced `pending`.
+    RightEnded { left: A, pending: XA, terminal: TB },
+}
@@ -1,9 +1,20 @@
 //! The async family of move iterator traits.
 
-mod endless;
-mod finite;
-mod terminal;
+pub mod endless;
+pub mod finite;
+mod local;
+#[cfg(feature = "futures")]
+mod stream;
+pub mod terminal;
 
 pub use self::endless::AsyncEndlessMoveIterator;
-pub use self::finite::AsyncFiniteMoveIterator;
-pub use self::terminal::AsyncTerminalMoveIterator;
+pub use self::finite::{AsyncFiniteMoveIterator, AsyncFiniteMoveIteratorExt};
+pub use self::local::{
+    LocalAsyncEndlessMoveIterator, LocalAsyncFiniteMoveIterator, LocalAsyncFiniteMoveIteratorExt,
+    LocalAsyncTerminalMoveIterator, LocalAsyncTerminalMoveIteratorExt,
+};
+#[cfg(feature = "futures")]
+pub use self::stream::{
+    async_finite_move_iterator_from_stream, async_terminal_move_iterator_from_try_stream,
+};
+pub use self::terminal::{AsyncTerminalMoveIterator, AsyncTerminalMoveIteratorExt};
@@ -1,7 +1,17 @@
-//! The [AsyncEndlessMoveIterator] trait.
+//! The [AsyncEndlessMoveIterator] trait and its constructors.
 
-use crate::adapters::{AemiAsFinite, AemiAsTerminal};
-use async_trait::async_trait;
+mod constructors;
+
+#[cfg(feature = "futures")]
+use crate::adapters::AemiStream;
+use crate::adapters::{
+    AemiAsBlocking, AemiAsFinite, AemiAsLocal, AemiAsTerminal, AemiFilterAsync, AemiFilterMapAsync,
+    AemiThen,
+};
+use crate::blocking::{BlockOn, ThreadParkExecutor};
+use std::future::Future;
+
+pub use self::constructors::{repeat_with, unfold};
 
 /// Produce an endless sequence of `Item` values asynchronously, using move semantics.
 ///
@@ -53,26 +63,30 @@ use async_trait::async_trait;
 /// note: this function takes ownership of the receiver `self`, which moves `it`
 ///   --> /home/user/hack/moveiter/src/asyn/endless.rs:60:24
 ///    |
-/// 60 |     async fn into_next(self) -> (Self, Self::Item);
-///    |                        ^^^^
+/// 60 |     fn into_next(self) -> impl Future<Output = (Self, Self::Item)> + Send;
+///    |                  ^^^^
 /// ```
-#[async_trait]
 pub trait AsyncEndlessMoveIterator: Sized + Send {
     type Item;
 
     /// Iteration is async, moves `self`, and produces an `Option<(Self, Self::Item)>`.
     ///
-    /// The simplified interface via [async_trait](::async_trait) looks like:
+    /// Implementations may write this as an `async fn`, which compiles to an unboxed state
+    /// machine:
+    ///
+    /// ```
+    /// # use moveiter::AsyncEndlessMoveIterator;
+    /// struct Counter(u64);
+    ///
+    /// impl AsyncEndlessMoveIterator for Counter {
+    ///     type Item = u64;
     ///
-    /// ```no_run
-    /// # use either::Either;
-    /// # #[async_trait::async_trait]
-    /// # trait T: Sized {
-    /// # type Item;
-    /// async fn into_next(self) -> (Self, Self::Item);
-    /// # }
+    ///     async fn into_next(self) -> (Self, Self::Item) {
+    ///         (Counter(self.0 + 1), self.0)
+    ///     }
+    /// }
     /// ```
-    async fn into_next(self) -> (Self, Self::Item);
+    fn into_next(self) -> impl Future<Output = (Self, Self::Item)> + Send;
 
     /// Adapt `self` into an [AsyncFiniteMoveIterator](crate::AsyncFiniteMoveIterator) which will never terminate.
     fn into_async_finite_move_iterator(self) -> AemiAsFinite<Self> {
@@ -83,4 +97,62 @@ pub trait AsyncEndlessMoveIterator: Sized + Send {
     fn into_async_terminal_move_iterator(self) -> AemiAsTerminal<Self> {
         AemiAsTerminal(self)
     }
+
+    /// Adapt `self` into a [LocalAsyncEndlessMoveIterator](crate::LocalAsyncEndlessMoveIterator), for
+    /// use alongside `!Send` iterators.
+    fn into_local(self) -> AemiAsLocal<Self> {
+        AemiAsLocal(self)
+    }
+
+    /// Adapt `self` into a [EndlessMoveIterator](crate::EndlessMoveIterator) which blocks the current
+    /// thread on each step with a [ThreadParkExecutor].
+    fn into_blocking(self) -> AemiAsBlocking<Self, ThreadParkExecutor> {
+        AemiAsBlocking(self, ThreadParkExecutor)
+    }
+
+    /// Adapt `self` into a [EndlessMoveIterator](crate::EndlessMoveIterator) which blocks the current
+    /// thread on each step with `executor`.
+    fn into_blocking_with<B>(self, executor: B) -> AemiAsBlocking<Self, B>
+    where
+        B: BlockOn,
+    {
+        AemiAsBlocking(self, executor)
+    }
+
+    /// Transform each `Item` with an async closure, awaiting each result in turn.
+    fn then<B, F, Fut>(self, f: F) -> AemiThen<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = B> + Send,
+    {
+        AemiThen(self, f)
+    }
+
+    /// Keep only the `Item`s for which the async predicate resolves to `true`.
+    fn filter_async<P, Fut>(self, pred: P) -> AemiFilterAsync<Self, P>
+    where
+        Self::Item: Send,
+        P: FnMut(&Self::Item) -> Fut + Send,
+        Fut: Future<Output = bool> + Send,
+    {
+        AemiFilterAsync(self, pred)
+    }
+
+    /// Transform each `Item` with an async closure, keeping only the `Some` results.
+    fn filter_map_async<B, F, Fut>(self, f: F) -> AemiFilterMapAsync<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = Option<B>> + Send,
+    {
+        AemiFilterMapAsync(self, f)
+    }
+
+    /// Convert `self` into a [Stream](futures::Stream) of `Item`s which never ends.
+    #[cfg(feature = "futures")]
+    fn into_stream<'a>(self) -> AemiStream<'a, Self>
+    where
+        Self: 'a,
+    {
+        AemiStream::new(self)
+    }
 }
@@ -0,0 +1,52 @@
+use crate::adapters::{AemiRepeatWith, AemiUnfold};
+use std::future::Future;
+
+/// Create an [AsyncEndlessMoveIterator](crate::AsyncEndlessMoveIterator) by threading `state`
+/// through `f`, whose future produces the next `(state, Item)` pair.
+pub fn unfold<S, F, Fut, B>(state: S, f: F) -> AemiUnfold<S, F>
+where
+    S: Send,
+    F: FnMut(S) -> Fut + Send,
+    Fut: Future<Output = (S, B)> + Send,
+{
+    AemiUnfold(state, f)
+}
+
+/// Create an [AsyncEndlessMoveIterator](crate::AsyncEndlessMoveIterator) which awaits a future
+/// from `f` for each `Item`.
+///
+/// # Example
+///
+/// Retry an operation until it succeeds:
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::AsyncEndlessMoveIterator;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// static CALLS: AtomicU32 = AtomicU32::new(0);
+///
+/// async fn attempt() -> Result<u32, &'static str> {
+///     let n = CALLS.fetch_add(1, Ordering::Relaxed);
+///     if n < 2 { Err("busy") } else { Ok(n) }
+/// }
+///
+/// let mut it = moveiter::asyn::endless::repeat_with(attempt);
+/// let value = loop {
+///     let (next, res) = it.into_next().await;
+///     match res {
+///         Ok(value) => break value,
+///         Err(_) => it = next,
+///     }
+/// };
+///
+/// assert_eq!(value, 2);
+/// # });
+/// ```
+pub fn repeat_with<F, Fut, B>(f: F) -> AemiRepeatWith<F>
+where
+    F: FnMut() -> Fut + Send,
+    Fut: Future<Output = B> + Send,
+{
+    AemiRepeatWith(f)
+}
@@ -1,7 +1,25 @@
-//! The [AsyncFiniteMoveIterator] trait.
+//! The [AsyncFiniteMoveIterator] and [AsyncFiniteMoveIteratorExt] traits and their constructors.
 
-use crate::adapters::AfmiAsTerminal;
-use async_trait::async_trait;
+mod constructors;
+
+use crate::adapters::{
+    AfmiAsBlocking, AfmiAsEndlessOr, AfmiAsLocal, AfmiAsTerminal, AfmiBufferUnordered,
+    AfmiBuffered, AfmiChain, AfmiFilterAsync, AfmiFilterMapAsync, AfmiPeekable, AfmiScan, AfmiThen,
+    AfmiZip, AfmiZipLongest,
+};
+#[cfg(feature = "futures")]
+use crate::adapters::{AfmiStream, ForwardError};
+use crate::blocking::{BlockOn, ThreadParkExecutor};
+use crate::futpool::FuturePool;
+use crate::FromTerminalMoveIterator;
+#[cfg(feature = "futures")]
+use futures::{Sink, SinkExt};
+use std::future::{poll_fn, Future};
+use std::ops::ControlFlow;
+use std::pin::pin;
+use std::task::Poll;
+
+pub use self::constructors::unfold;
 
 /// Produce a sequence of 0 or more `Item` values asynchronously, using move semantics.
 ///
@@ -72,26 +90,30 @@ use async_trait::async_trait;
 /// note: this function takes ownership of the receiver `self`, which moves `it`
 ///   --> /home/user/hack/moveiter/src/asyn/finite.rs:78:24
 ///    |
-/// 78 |     async fn into_next(self) -> Option<(Self, Self::Item)>;
-///    |                        ^^^^
+/// 78 |     fn into_next(self) -> impl Future<Output = Option<(Self, Self::Item)>> + Send;
+///    |                  ^^^^
 /// ```
-#[async_trait]
 pub trait AsyncFiniteMoveIterator: Sized + Send {
     type Item;
 
     /// Iteration is async, moves `self`, and produces an `Option<(Self, Self::Item)>`.
     ///
-    /// The simplified interface via [async_trait](::async_trait) looks like:
+    /// Implementations may write this as an `async fn`, which compiles to an unboxed state
+    /// machine:
+    ///
+    /// ```
+    /// # use moveiter::AsyncFiniteMoveIterator;
+    /// struct Countdown(u32);
+    ///
+    /// impl AsyncFiniteMoveIterator for Countdown {
+    ///     type Item = u32;
     ///
-    /// ```no_run
-    /// # use either::Either;
-    /// # #[async_trait::async_trait]
-    /// # trait T: Sized {
-    /// # type Item;
-    /// async fn into_next(self) -> Option<(Self, Self::Item)>;
-    /// # }
+    ///     async fn into_next(self) -> Option<(Self, Self::Item)> {
+    ///         self.0.checked_sub(1).map(|n| (Countdown(n), n))
+    ///     }
+    /// }
     /// ```
-    async fn into_next(self) -> Option<(Self, Self::Item)>;
+    fn into_next(self) -> impl Future<Output = Option<(Self, Self::Item)>> + Send;
 
     /// Adapt `self` into an [AsyncTerminalMoveIterator](crate::AsyncTerminalMoveIterator) with `Terminal = ()`.
     fn into_async_terminal_move_iterator(self) -> AfmiAsTerminal<Self> {
@@ -99,7 +121,568 @@ pub trait AsyncFiniteMoveIterator: Sized + Send {
     }
 }
 
-#[async_trait]
+/// The adapters and consumers of an [AsyncFiniteMoveIterator], implemented for every
+/// [AsyncFiniteMoveIterator].
+///
+/// As with [FiniteMoveIteratorExt](crate::FiniteMoveIteratorExt), the names which an [Iterator]
+/// shares, such as `zip` or `collect`, are resolved on an [Iterator] value by converting it with
+/// [into_async](crate::FiniteMoveIterator::into_async) first.
+pub trait AsyncFiniteMoveIteratorExt: AsyncFiniteMoveIterator {
+    /// Adapt `self` into a [LocalAsyncFiniteMoveIterator](crate::LocalAsyncFiniteMoveIterator), for
+    /// use alongside `!Send` iterators.
+    fn into_local(self) -> AfmiAsLocal<Self> {
+        AfmiAsLocal(self)
+    }
+
+    /// Adapt `self` into a [FiniteMoveIterator](crate::FiniteMoveIterator) which blocks the current
+    /// thread on each step with a [ThreadParkExecutor].
+    fn into_blocking(self) -> AfmiAsBlocking<Self, ThreadParkExecutor> {
+        AfmiAsBlocking(self, ThreadParkExecutor)
+    }
+
+    /// Adapt `self` into a [FiniteMoveIterator](crate::FiniteMoveIterator) which blocks the current
+    /// thread on each step with `executor`.
+    fn into_blocking_with<B>(self, executor: B) -> AfmiAsBlocking<Self, B>
+    where
+        B: BlockOn,
+    {
+        AfmiAsBlocking(self, executor)
+    }
+
+    /// Adapt `self` into an [AsyncEndlessMoveIterator](crate::AsyncEndlessMoveIterator) which produces
+    /// each `Item`, then produces `fallback()` forever after.
+    fn into_endless_or<F>(self, fallback: F) -> AfmiAsEndlessOr<Self, F>
+    where
+        F: FnMut() -> Self::Item + Send,
+    {
+        AfmiAsEndlessOr(Some(self), fallback)
+    }
+
+    /// Produce each `Item` of `self`, then each `Item` of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncFiniteMoveIterator, AsyncFiniteMoveIteratorExt, FiniteMoveIterator};
+    ///
+    /// let mut it = (0..2).into_async().chain(5..6);
+    /// let mut items = vec![];
+    ///
+    /// while let Some((next, x)) = it.into_next().await {
+    ///     it = next;
+    ///     items.push(x);
+    /// }
+    ///
+    /// assert_eq!(items, vec![0, 1, 5]);
+    /// # });
+    /// ```
+    fn chain<J>(self, other: J) -> AfmiChain<Self, J>
+    where
+        J: AsyncFiniteMoveIterator<Item = Self::Item>,
+    {
+        AfmiChain(either::Either::Left((self, other)))
+    }
+
+    /// Produce pairs of `Item`s from `self` and `other` until either ends.
+    ///
+    /// The result is an [AsyncTerminalMoveIterator](crate::AsyncTerminalMoveIterator) whose
+    /// [ZipTerminal](crate::adapters::ZipTerminal) identifies which side ended first, and carries
+    /// the remainder of the other side.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::adapters::ZipTerminal;
+    /// use moveiter::{
+    ///     AsyncFiniteMoveIterator, AsyncFiniteMoveIteratorExt, AsyncTerminalMoveIterator,
+    ///     FiniteMoveIterator,
+    /// };
+    ///
+    /// let it = (0..3).into_async().zip(vec!["a"].into_iter());
+    ///
+    /// let (it, pair) = it.into_next().await.left().unwrap();
+    /// assert_eq!(pair, (0, "a"));
+    ///
+    /// match it.into_next().await.right().unwrap() {
+    ///     ZipTerminal::RightEnded { left, pending, .. } => {
+    ///         assert_eq!(pending, 1);
+    ///         let (left, x) = left.into_next().await.unwrap();
+    ///         assert_eq!(x, 2);
+    ///         assert!(left.into_next().await.is_none());
+    ///     }
+    ///     ZipTerminal::LeftEnded { .. } => unreachable!(),
+    /// }
+    /// # });
+    /// ```
+    fn zip<J>(self, other: J) -> AfmiZip<Self, J>
+    where
+        J: AsyncFiniteMoveIterator,
+    {
+        AfmiZip(self, other)
+    }
+
+    /// Produce [EitherOrBoth](crate::adapters::EitherOrBoth) `Item`s from `self` and `other`
+    /// until both end.
+    fn zip_longest<J>(self, other: J) -> AfmiZipLongest<Self, J>
+    where
+        J: AsyncFiniteMoveIterator,
+    {
+        AfmiZipLongest::new(self, other)
+    }
+
+    /// Adapt `self` to support lookahead with [AfmiPeekable::into_peek] and
+    /// [AfmiPeekable::peeked], and to put `Item`s back in front with [AfmiPeekable::push_back].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncFiniteMoveIterator, AsyncFiniteMoveIteratorExt, FiniteMoveIterator};
+    ///
+    /// let it = vec!['a', '1'].into_iter().into_async().peekable();
+    ///
+    /// let it = it.into_peek().await;
+    /// assert_eq!(it.peeked(), Some(&'a'));
+    /// let (mut it, c) = it.into_next().await.unwrap();
+    /// assert_eq!(c, 'a');
+    ///
+    /// it.push_back('z');
+    /// let (it, c) = it.into_next().await.unwrap();
+    /// assert_eq!(c, 'z');
+    ///
+    /// let it = it.into_peek().await;
+    /// assert_eq!(it.peeked(), Some(&'1'));
+    /// let (it, _) = it.into_next().await.unwrap();
+    /// assert_eq!(it.into_peek().await.peeked(), None);
+    /// # });
+    /// ```
+    fn peekable(self) -> AfmiPeekable<Self> {
+        AfmiPeekable::new(self)
+    }
+
+    /// Thread a `state` through the async `f` on each `Item`, producing the `Some` results of `f`.
+    ///
+    /// Because `f` is async, it takes the state by value and returns it along with its optional
+    /// result. Returning `None` skips an `Item` rather than ending iteration. The result is an
+    /// [AsyncTerminalMoveIterator](crate::AsyncTerminalMoveIterator) whose `Terminal` is the
+    /// final state.
+    fn scan<S, B, F, Fut>(self, init: S, f: F) -> AfmiScan<Self, S, F>
+    where
+        S: Send,
+        F: FnMut(S, Self::Item) -> Fut + Send,
+        Fut: Future<Output = (S, Option<B>)> + Send,
+    {
+        AfmiScan(self, init, f)
+    }
+
+    /// Transform each `Item` with an async closure, awaiting each result in turn.
+    fn then<B, F, Fut>(self, f: F) -> AfmiThen<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = B> + Send,
+    {
+        AfmiThen(self, f)
+    }
+
+    /// Keep only the `Item`s for which the async predicate resolves to `true`.
+    fn filter_async<P, Fut>(self, pred: P) -> AfmiFilterAsync<Self, P>
+    where
+        Self::Item: Send,
+        P: FnMut(&Self::Item) -> Fut + Send,
+        Fut: Future<Output = bool> + Send,
+    {
+        AfmiFilterAsync(self, pred)
+    }
+
+    /// Transform each `Item` with an async closure, keeping only the `Some` results.
+    fn filter_map_async<B, F, Fut>(self, f: F) -> AfmiFilterMapAsync<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = Option<B>> + Send,
+    {
+        AfmiFilterMapAsync(self, f)
+    }
+
+    /// Await the `Item` futures with up to `limit` in flight at once, yielding their outputs in
+    /// the original order.
+    ///
+    /// The source is only advanced while fewer than `limit` futures are in flight. The in-flight
+    /// futures keep making progress while the source is pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use futures::FutureExt;
+    /// use moveiter::{AsyncFiniteMoveIterator, AsyncFiniteMoveIteratorExt};
+    ///
+    /// let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    /// let it = moveiter::asyn::finite::unfold(rx, |mut rx| async move {
+    ///     let x = rx.recv().await?;
+    ///     Some((rx, async move { x * 10 }))
+    /// })
+    /// .buffered(4);
+    ///
+    /// // Only one item has arrived and the sender is still open, yet it is produced at once:
+    /// tx.send(1).unwrap();
+    /// let (it, x) = it.into_next().now_or_never().unwrap().unwrap();
+    /// assert_eq!(x, 10);
+    ///
+    /// drop(tx);
+    /// assert!(it.into_next().await.is_none());
+    /// # });
+    /// ```
+    fn buffered<'a>(self, limit: usize) -> AfmiBuffered<'a, Self>
+    where
+        Self: 'a,
+        Self::Item: Future,
+    {
+        AfmiBuffered::new(self, limit)
+    }
+
+    /// Await the `Item` futures with up to `limit` in flight at once, yielding their outputs as
+    /// they finish.
+    ///
+    /// The source is only advanced while fewer than `limit` futures are in flight. The in-flight
+    /// futures keep making progress while the source is pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    fn buffer_unordered<'a>(self, limit: usize) -> AfmiBufferUnordered<'a, Self>
+    where
+        Self: 'a,
+        Self::Item: Future,
+    {
+        AfmiBufferUnordered::new(self, limit)
+    }
+
+    /// Convert `self` into a [Stream](futures::Stream) of `Item`s.
+    #[cfg(feature = "futures")]
+    fn into_stream<'a>(self) -> AfmiStream<'a, Self>
+    where
+        Self: 'a,
+    {
+        AfmiStream::new(self)
+    }
+
+    /// Call a closure on each `Item`.
+    fn for_each<F>(self, mut f: F) -> impl Future<Output = ()> + Send
+    where
+        F: FnMut(Self::Item) + Send,
+    {
+        async move {
+            let mut it = self;
+
+            while let Some((next, x)) = it.into_next().await {
+                it = next;
+                f(x);
+            }
+        }
+    }
+
+    /// Call an async closure on each `Item`, awaiting each result in turn.
+    fn for_each_async<F, Fut>(self, mut f: F) -> impl Future<Output = ()> + Send
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+    {
+        async move {
+            let mut it = self;
+
+            loop {
+                let (next, x) = match it.into_next().await {
+                    Some(next) => next,
+                    None => {
+                        return;
+                    }
+                };
+                it = next;
+                f(x).await;
+            }
+        }
+    }
+
+    /// Call an async closure on each `Item`, with up to `limit` of the resulting futures in
+    /// flight at once, returning once they have all finished.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    ///
+    /// # Example
+    ///
+    /// The in-flight futures keep running while the source is pending, so a source may wait on
+    /// the work it has already handed out:
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// use moveiter::AsyncFiniteMoveIteratorExt;
+    /// use std::sync::Mutex;
+    /// use std::time::Duration;
+    ///
+    /// let (ack_tx, ack_rx) = tokio::sync::mpsc::unbounded_channel();
+    /// let it = moveiter::asyn::finite::unfold((0, ack_rx), |(n, mut ack_rx)| async move {
+    ///     if n > 0 {
+    ///         // Wait until the previous item has been handled:
+    ///         ack_rx.recv().await?;
+    ///     }
+    ///     if n < 3 {
+    ///         Some(((n + 1, ack_rx), n))
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    ///
+    /// let handled = Mutex::new(vec![]);
+    /// let done = it.for_each_concurrent(2, |x| {
+    ///     let (ack_tx, handled) = (ack_tx.clone(), &handled);
+    ///     async move {
+    ///         handled.lock().unwrap().push(x);
+    ///         ack_tx.send(()).unwrap();
+    ///     }
+    /// });
+    ///
+    /// tokio::time::timeout(Duration::from_secs(5), done).await.unwrap();
+    /// assert_eq!(handled.into_inner().unwrap(), vec![0, 1, 2]);
+    /// # });
+    /// ```
+    fn for_each_concurrent<F, Fut>(self, limit: usize, mut f: F) -> impl Future<Output = ()> + Send
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+    {
+        async move {
+            assert!(
+                limit > 0,
+                "for_each_concurrent requires a limit of at least 1"
+            );
+
+            let mut pool = FuturePool::new();
+            let mut step = pin!(self.into_next());
+
+            // Race the source against the pool, so that the in-flight futures keep making progress
+            // while the source is pending:
+            while let Some((next, x)) = poll_fn(|cx| {
+                while let Poll::Ready(Some(())) = pool.poll_next_unordered(cx) {}
+                if pool.len() < limit {
+                    step.as_mut().poll(cx)
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await
+            {
+                pool.push(f(x));
+                step.set(next.into_next());
+            }
+
+            while pool.next_unordered().await.is_some() {}
+        }
+    }
+
+    /// Send each `Item` into `sink`, then close it.
+    ///
+    /// The sink is flushed after each `Item`, before awaiting the next one, so a buffering sink
+    /// delivers items while the source is still producing. If `sink` rejects an `Item`, the
+    /// remainder of `self` is returned in [ForwardError::Send].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use futures::{channel::mpsc, SinkExt};
+    /// use moveiter::AsyncFiniteMoveIteratorExt;
+    ///
+    /// let (tx, mut rx) = mpsc::unbounded();
+    /// let sink = tx.buffer(16);
+    ///
+    /// // Each step checks that the previous item has already reached the receiver:
+    /// let source = moveiter::asyn::finite::unfold(0, |n| {
+    ///     if n > 0 {
+    ///         assert_eq!(rx.try_recv().ok(), Some(n - 1));
+    ///     }
+    ///     async move { if n < 3 { Some((n + 1, n)) } else { None } }
+    /// });
+    ///
+    /// assert!(source.forward(sink).await.is_ok());
+    /// # });
+    /// ```
+    #[cfg(feature = "futures")]
+    fn forward<S>(
+        self,
+        mut sink: S,
+    ) -> impl Future<Output = Result<(), ForwardError<Self, (), S::Error>>> + Send
+    where
+        S: Sink<Self::Item> + Unpin + Send,
+        Self::Item: Send,
+    {
+        async move {
+            let mut it = self;
+
+            while let Some((next, x)) = it.into_next().await {
+                if let Err(e) = sink.send(x).await {
+                    return Err(ForwardError::Send(next, e));
+                }
+                it = next;
+            }
+
+            sink.close().await.map_err(|e| ForwardError::Close((), e))
+        }
+    }
+
+    /// Folds every `Item` into an accumulator with `f`, then returns the final accumulator.
+    fn fold<A, F>(self, mut acc: A, mut f: F) -> impl Future<Output = A> + Send
+    where
+        A: Send,
+        F: FnMut(A, Self::Item) -> A + Send,
+    {
+        async move {
+            let mut it = self;
+
+            while let Some((next, x)) = it.into_next().await {
+                it = next;
+                acc = f(acc, x);
+            }
+
+            acc
+        }
+    }
+
+    /// Folds every `Item` into an accumulator with the async `f`, then returns the final
+    /// accumulator.
+    fn fold_async<A, F, Fut>(self, mut acc: A, mut f: F) -> impl Future<Output = A> + Send
+    where
+        A: Send,
+        F: FnMut(A, Self::Item) -> Fut + Send,
+        Fut: Future<Output = A> + Send,
+    {
+        async move {
+            let mut it = self;
+
+            loop {
+                let (next, x) = match it.into_next().await {
+                    Some(next) => next,
+                    None => {
+                        return acc;
+                    }
+                };
+                it = next;
+                acc = f(acc, x).await;
+            }
+        }
+    }
+
+    /// Count the `Item`s.
+    fn count(self) -> impl Future<Output = usize> + Send {
+        async move { self.fold(0, |n, _| n + 1).await }
+    }
+
+    /// Return the last `Item`, if any.
+    fn last(self) -> impl Future<Output = Option<Self::Item>> + Send
+    where
+        Self::Item: Send,
+    {
+        async move { self.fold(None, |_, x| Some(x)).await }
+    }
+
+    /// Collect every `Item` into `C`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncFiniteMoveIteratorExt, FiniteMoveIterator};
+    ///
+    /// let it = (0..2).into_async().chain(5..7);
+    ///
+    /// let v: Vec<_> = it.collect().await;
+    /// assert_eq!(v, vec![0, 1, 5, 6]);
+    /// # });
+    /// ```
+    fn collect<C>(self) -> impl Future<Output = C> + Send
+    where
+        C: FromTerminalMoveIterator<Self::Item> + Send,
+    {
+        async move {
+            self.fold(C::default(), |mut collection, x| {
+                collection.extend(Some(x));
+                collection
+            })
+            .await
+        }
+    }
+
+    /// Call a closure on each `Item` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value.
+    fn try_for_each<B, F>(self, mut f: F) -> impl Future<Output = ControlFlow<(Self, B)>> + Send
+    where
+        F: FnMut(Self::Item) -> ControlFlow<B> + Send,
+    {
+        async move {
+            use ControlFlow::{Break, Continue};
+
+            let mut it = self;
+
+            while let Some((next, x)) = it.into_next().await {
+                match f(x) {
+                    Continue(()) => {
+                        it = next;
+                    }
+                    Break(b) => {
+                        return Break((next, b));
+                    }
+                }
+            }
+
+            Continue(())
+        }
+    }
+
+    /// Folds `Item`s into an accumulator with `f` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value, otherwise
+    /// the final accumulator is returned.
+    fn try_fold<A, B, F>(
+        self,
+        mut acc: A,
+        mut f: F,
+    ) -> impl Future<Output = ControlFlow<(Self, B), A>> + Send
+    where
+        A: Send,
+        F: FnMut(A, Self::Item) -> ControlFlow<B, A> + Send,
+    {
+        async move {
+            use ControlFlow::{Break, Continue};
+
+            let mut it = self;
+
+            while let Some((next, x)) = it.into_next().await {
+                match f(acc, x) {
+                    Continue(a) => {
+                        it = next;
+                        acc = a;
+                    }
+                    Break(b) => {
+                        return Break((next, b));
+                    }
+                }
+            }
+
+            Continue(acc)
+        }
+    }
+}
+
+impl<I> AsyncFiniteMoveIteratorExt for I where I: AsyncFiniteMoveIterator {}
+
 impl<I> AsyncFiniteMoveIterator for I
 where
     I: Iterator + Sized + Send,
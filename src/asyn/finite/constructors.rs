@@ -0,0 +1,13 @@
+use crate::adapters::AfmiUnfold;
+use std::future::Future;
+
+/// Create an [AsyncFiniteMoveIterator](crate::AsyncFiniteMoveIterator) by threading `state`
+/// through `f`, whose future produces the next `(state, Item)` pair, or `None` to end.
+pub fn unfold<S, F, Fut, B>(state: S, f: F) -> AfmiUnfold<S, F>
+where
+    S: Send,
+    F: FnMut(S) -> Fut + Send,
+    Fut: Future<Output = Option<(S, B)>> + Send,
+{
+    AfmiUnfold(state, f)
+}
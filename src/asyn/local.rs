@@ -0,0 +1,9 @@
+//! The `!Send` counterparts of the async move iterator traits, for single-threaded executors.
+
+mod endless;
+mod finite;
+mod terminal;
+
+pub use self::endless::LocalAsyncEndlessMoveIterator;
+pub use self::finite::{LocalAsyncFiniteMoveIterator, LocalAsyncFiniteMoveIteratorExt};
+pub use self::terminal::{LocalAsyncTerminalMoveIterator, LocalAsyncTerminalMoveIteratorExt};
@@ -0,0 +1,89 @@
+//! The [LocalAsyncEndlessMoveIterator] trait.
+
+use crate::adapters::{
+    LaemiAsBlocking, LaemiAsFinite, LaemiAsTerminal, LaemiFilterAsync, LaemiFilterMapAsync,
+    LaemiThen,
+};
+use crate::blocking::{BlockOn, ThreadParkExecutor};
+use std::future::Future;
+
+/// Produce an endless sequence of `Item` values asynchronously, using move semantics.
+///
+/// This is the counterpart of [AsyncEndlessMoveIterator](crate::AsyncEndlessMoveIterator) without
+/// `Send` bounds, for iterators holding `Rc`, `RefCell`, or other `!Send` state on a
+/// single-threaded executor. Any `AsyncEndlessMoveIterator` converts with
+/// [into_local](crate::AsyncEndlessMoveIterator::into_local).
+pub trait LocalAsyncEndlessMoveIterator: Sized {
+    type Item;
+
+    /// Iteration is async, moves `self`, and produces an `Option<(Self, Self::Item)>`.
+    ///
+    /// Implementations may write this as an `async fn`, which compiles to an unboxed state
+    /// machine:
+    ///
+    /// ```
+    /// # use moveiter::LocalAsyncEndlessMoveIterator;
+    /// struct Counter(u64);
+    ///
+    /// impl LocalAsyncEndlessMoveIterator for Counter {
+    ///     type Item = u64;
+    ///
+    ///     async fn into_next(self) -> (Self, Self::Item) {
+    ///         (Counter(self.0 + 1), self.0)
+    ///     }
+    /// }
+    /// ```
+    fn into_next(self) -> impl Future<Output = (Self, Self::Item)>;
+
+    /// Adapt `self` into a [EndlessMoveIterator](crate::EndlessMoveIterator) which blocks the current
+    /// thread on each step with a [ThreadParkExecutor].
+    fn into_blocking(self) -> LaemiAsBlocking<Self, ThreadParkExecutor> {
+        LaemiAsBlocking(self, ThreadParkExecutor)
+    }
+
+    /// Adapt `self` into a [EndlessMoveIterator](crate::EndlessMoveIterator) which blocks the current
+    /// thread on each step with `executor`.
+    fn into_blocking_with<B>(self, executor: B) -> LaemiAsBlocking<Self, B>
+    where
+        B: BlockOn,
+    {
+        LaemiAsBlocking(self, executor)
+    }
+
+    /// Adapt `self` into a [LocalAsyncFiniteMoveIterator](crate::LocalAsyncFiniteMoveIterator) which will never terminate.
+    fn into_async_finite_move_iterator(self) -> LaemiAsFinite<Self> {
+        LaemiAsFinite(self)
+    }
+
+    /// Adapt `self` into a [LocalAsyncTerminalMoveIterator](crate::LocalAsyncTerminalMoveIterator) which will never terminate.
+    fn into_async_terminal_move_iterator(self) -> LaemiAsTerminal<Self> {
+        LaemiAsTerminal(self)
+    }
+
+    /// Transform each `Item` with an async closure, awaiting each result in turn.
+    fn then<B, F, Fut>(self, f: F) -> LaemiThen<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        LaemiThen(self, f)
+    }
+
+    /// Keep only the `Item`s for which the async predicate resolves to `true`.
+    fn filter_async<P, Fut>(self, pred: P) -> LaemiFilterAsync<Self, P>
+    where
+        P: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        LaemiFilterAsync(self, pred)
+    }
+
+    /// Transform each `Item` with an async closure, keeping only the `Some` results.
+    fn filter_map_async<B, F, Fut>(self, f: F) -> LaemiFilterMapAsync<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = Option<B>>,
+    {
+        LaemiFilterMapAsync(self, f)
+    }
+}
@@ -0,0 +1,420 @@
+//! The [LocalAsyncFiniteMoveIterator] and [LocalAsyncFiniteMoveIteratorExt] traits.
+
+use crate::adapters::{
+    LafmiAsBlocking, LafmiAsEndlessOr, LafmiAsTerminal, LafmiBufferUnordered, LafmiBuffered,
+    LafmiChain, LafmiFilterAsync, LafmiFilterMapAsync, LafmiPeekable, LafmiScan, LafmiThen,
+    LafmiZip, LafmiZipLongest,
+};
+use crate::blocking::{BlockOn, ThreadParkExecutor};
+use crate::futpool::FuturePool;
+use crate::FromTerminalMoveIterator;
+use std::future::{poll_fn, Future};
+use std::ops::ControlFlow;
+use std::pin::pin;
+use std::task::Poll;
+
+/// Produce a sequence of 0 or more `Item` values asynchronously, using move semantics.
+///
+/// This is the counterpart of [AsyncFiniteMoveIterator](crate::AsyncFiniteMoveIterator) without
+/// `Send` bounds, for iterators holding `Rc`, `RefCell`, or other `!Send` state on a
+/// single-threaded executor. Any [Iterator] is a `LocalAsyncFiniteMoveIterator`, and any
+/// `AsyncFiniteMoveIterator` converts with
+/// [into_local](crate::AsyncFiniteMoveIteratorExt::into_local).
+///
+/// # Example
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::{AsyncFiniteMoveIteratorExt, FiniteMoveIterator, LocalAsyncFiniteMoveIteratorExt};
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// let total = Rc::new(Cell::new(0));
+/// let it = (0..2).into_async().into_local().chain(5..6);
+///
+/// let t = total.clone();
+/// it.for_each(move |x| t.set(t.get() + x)).await;
+///
+/// assert_eq!(total.get(), 6);
+/// # });
+/// ```
+pub trait LocalAsyncFiniteMoveIterator: Sized {
+    type Item;
+
+    /// Iteration is async, moves `self`, and produces an `Option<(Self, Self::Item)>`.
+    ///
+    /// Implementations may write this as an `async fn`, which compiles to an unboxed state
+    /// machine:
+    ///
+    /// ```
+    /// # use moveiter::LocalAsyncFiniteMoveIterator;
+    /// struct Countdown(u32);
+    ///
+    /// impl LocalAsyncFiniteMoveIterator for Countdown {
+    ///     type Item = u32;
+    ///
+    ///     async fn into_next(self) -> Option<(Self, Self::Item)> {
+    ///         self.0.checked_sub(1).map(|n| (Countdown(n), n))
+    ///     }
+    /// }
+    /// ```
+    fn into_next(self) -> impl Future<Output = Option<(Self, Self::Item)>>;
+
+    /// Adapt `self` into a [LocalAsyncTerminalMoveIterator](crate::LocalAsyncTerminalMoveIterator) with `Terminal = ()`.
+    fn into_async_terminal_move_iterator(self) -> LafmiAsTerminal<Self> {
+        LafmiAsTerminal(self)
+    }
+}
+
+/// The adapters and consumers of a [LocalAsyncFiniteMoveIterator], implemented for every
+/// [LocalAsyncFiniteMoveIterator].
+pub trait LocalAsyncFiniteMoveIteratorExt: LocalAsyncFiniteMoveIterator {
+    /// Adapt `self` into a [FiniteMoveIterator](crate::FiniteMoveIterator) which blocks the current
+    /// thread on each step with a [ThreadParkExecutor].
+    fn into_blocking(self) -> LafmiAsBlocking<Self, ThreadParkExecutor> {
+        LafmiAsBlocking(self, ThreadParkExecutor)
+    }
+
+    /// Adapt `self` into a [FiniteMoveIterator](crate::FiniteMoveIterator) which blocks the current
+    /// thread on each step with `executor`.
+    fn into_blocking_with<B>(self, executor: B) -> LafmiAsBlocking<Self, B>
+    where
+        B: BlockOn,
+    {
+        LafmiAsBlocking(self, executor)
+    }
+
+    /// Adapt `self` into a [LocalAsyncEndlessMoveIterator](crate::LocalAsyncEndlessMoveIterator) which produces
+    /// each `Item`, then produces `fallback()` forever after.
+    fn into_endless_or<F>(self, fallback: F) -> LafmiAsEndlessOr<Self, F>
+    where
+        F: FnMut() -> Self::Item,
+    {
+        LafmiAsEndlessOr(Some(self), fallback)
+    }
+
+    /// Produce each `Item` of `self`, then each `Item` of `other`.
+    fn chain<J>(self, other: J) -> LafmiChain<Self, J>
+    where
+        J: LocalAsyncFiniteMoveIterator<Item = Self::Item>,
+    {
+        LafmiChain(either::Either::Left((self, other)))
+    }
+
+    /// Produce pairs of `Item`s from `self` and `other` until either ends.
+    ///
+    /// The result is a [LocalAsyncTerminalMoveIterator](crate::LocalAsyncTerminalMoveIterator) whose
+    /// [ZipTerminal](crate::adapters::ZipTerminal) identifies which side ended first, and carries
+    /// the remainder of the other side.
+    fn zip<J>(self, other: J) -> LafmiZip<Self, J>
+    where
+        J: LocalAsyncFiniteMoveIterator,
+    {
+        LafmiZip(self, other)
+    }
+
+    /// Produce [EitherOrBoth](crate::adapters::EitherOrBoth) `Item`s from `self` and `other`
+    /// until both end.
+    fn zip_longest<J>(self, other: J) -> LafmiZipLongest<Self, J>
+    where
+        J: LocalAsyncFiniteMoveIterator,
+    {
+        LafmiZipLongest::new(self, other)
+    }
+
+    /// Adapt `self` to support lookahead with [LafmiPeekable::into_peek] and
+    /// [LafmiPeekable::peeked], and to put `Item`s back in front with [LafmiPeekable::push_back].
+    fn peekable(self) -> LafmiPeekable<Self> {
+        LafmiPeekable::new(self)
+    }
+
+    /// Thread a `state` through the async `f` on each `Item`, producing the `Some` results of `f`.
+    ///
+    /// Because `f` is async, it takes the state by value and returns it along with its optional
+    /// result. Returning `None` skips an `Item` rather than ending iteration. The result is an
+    /// [LocalAsyncTerminalMoveIterator](crate::LocalAsyncTerminalMoveIterator) whose `Terminal` is the
+    /// final state.
+    fn scan<S, B, F, Fut>(self, init: S, f: F) -> LafmiScan<Self, S, F>
+    where
+        F: FnMut(S, Self::Item) -> Fut,
+        Fut: Future<Output = (S, Option<B>)>,
+    {
+        LafmiScan(self, init, f)
+    }
+
+    /// Transform each `Item` with an async closure, awaiting each result in turn.
+    fn then<B, F, Fut>(self, f: F) -> LafmiThen<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        LafmiThen(self, f)
+    }
+
+    /// Keep only the `Item`s for which the async predicate resolves to `true`.
+    fn filter_async<P, Fut>(self, pred: P) -> LafmiFilterAsync<Self, P>
+    where
+        P: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        LafmiFilterAsync(self, pred)
+    }
+
+    /// Transform each `Item` with an async closure, keeping only the `Some` results.
+    fn filter_map_async<B, F, Fut>(self, f: F) -> LafmiFilterMapAsync<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = Option<B>>,
+    {
+        LafmiFilterMapAsync(self, f)
+    }
+
+    /// Await the `Item` futures with up to `limit` in flight at once, yielding their outputs in
+    /// the original order.
+    ///
+    /// The source is only advanced while fewer than `limit` futures are in flight. The in-flight
+    /// futures keep making progress while the source is pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    fn buffered<'a>(self, limit: usize) -> LafmiBuffered<'a, Self>
+    where
+        Self: 'a,
+        Self::Item: Future,
+    {
+        LafmiBuffered::new(self, limit)
+    }
+
+    /// Await the `Item` futures with up to `limit` in flight at once, yielding their outputs as
+    /// they finish.
+    ///
+    /// The source is only advanced while fewer than `limit` futures are in flight. The in-flight
+    /// futures keep making progress while the source is pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    fn buffer_unordered<'a>(self, limit: usize) -> LafmiBufferUnordered<'a, Self>
+    where
+        Self: 'a,
+        Self::Item: Future,
+    {
+        LafmiBufferUnordered::new(self, limit)
+    }
+
+    /// Call a closure on each `Item`.
+    fn for_each<F>(self, mut f: F) -> impl Future<Output = ()>
+    where
+        F: FnMut(Self::Item),
+    {
+        async move {
+            let mut it = self;
+
+            while let Some((next, x)) = it.into_next().await {
+                it = next;
+                f(x);
+            }
+        }
+    }
+
+    /// Call an async closure on each `Item`, awaiting each result in turn.
+    fn for_each_async<F, Fut>(self, mut f: F) -> impl Future<Output = ()>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        async move {
+            let mut it = self;
+
+            loop {
+                let (next, x) = match it.into_next().await {
+                    Some(next) => next,
+                    None => {
+                        return;
+                    }
+                };
+                it = next;
+                f(x).await;
+            }
+        }
+    }
+
+    /// Call an async closure on each `Item`, with up to `limit` of the resulting futures in
+    /// flight at once, returning once they have all finished.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    fn for_each_concurrent<F, Fut>(self, limit: usize, mut f: F) -> impl Future<Output = ()>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        async move {
+            assert!(
+                limit > 0,
+                "for_each_concurrent requires a limit of at least 1"
+            );
+
+            let mut pool = FuturePool::new();
+            let mut step = pin!(self.into_next());
+
+            // Race the source against the pool, so that the in-flight futures keep making progress
+            // while the source is pending:
+            while let Some((next, x)) = poll_fn(|cx| {
+                while let Poll::Ready(Some(())) = pool.poll_next_unordered(cx) {}
+                if pool.len() < limit {
+                    step.as_mut().poll(cx)
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await
+            {
+                pool.push(f(x));
+                step.set(next.into_next());
+            }
+
+            while pool.next_unordered().await.is_some() {}
+        }
+    }
+
+    /// Folds every `Item` into an accumulator with `f`, then returns the final accumulator.
+    fn fold<A, F>(self, mut acc: A, mut f: F) -> impl Future<Output = A>
+    where
+        F: FnMut(A, Self::Item) -> A,
+    {
+        async move {
+            let mut it = self;
+
+            while let Some((next, x)) = it.into_next().await {
+                it = next;
+                acc = f(acc, x);
+            }
+
+            acc
+        }
+    }
+
+    /// Folds every `Item` into an accumulator with the async `f`, then returns the final
+    /// accumulator.
+    fn fold_async<A, F, Fut>(self, mut acc: A, mut f: F) -> impl Future<Output = A>
+    where
+        F: FnMut(A, Self::Item) -> Fut,
+        Fut: Future<Output = A>,
+    {
+        async move {
+            let mut it = self;
+
+            loop {
+                let (next, x) = match it.into_next().await {
+                    Some(next) => next,
+                    None => {
+                        return acc;
+                    }
+                };
+                it = next;
+                acc = f(acc, x).await;
+            }
+        }
+    }
+
+    /// Count the `Item`s.
+    fn count(self) -> impl Future<Output = usize> {
+        async move { self.fold(0, |n, _| n + 1).await }
+    }
+
+    /// Return the last `Item`, if any.
+    fn last(self) -> impl Future<Output = Option<Self::Item>>
+where {
+        async move { self.fold(None, |_, x| Some(x)).await }
+    }
+
+    /// Collect every `Item` into `C`.
+    fn collect<C>(self) -> impl Future<Output = C>
+    where
+        C: FromTerminalMoveIterator<Self::Item>,
+    {
+        async move {
+            self.fold(C::default(), |mut collection, x| {
+                collection.extend(Some(x));
+                collection
+            })
+            .await
+        }
+    }
+
+    /// Call a closure on each `Item` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value.
+    fn try_for_each<B, F>(self, mut f: F) -> impl Future<Output = ControlFlow<(Self, B)>>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<B>,
+    {
+        async move {
+            use ControlFlow::{Break, Continue};
+
+            let mut it = self;
+
+            while let Some((next, x)) = it.into_next().await {
+                match f(x) {
+                    Continue(()) => {
+                        it = next;
+                    }
+                    Break(b) => {
+                        return Break((next, b));
+                    }
+                }
+            }
+
+            Continue(())
+        }
+    }
+
+    /// Folds `Item`s into an accumulator with `f` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value, otherwise
+    /// the final accumulator is returned.
+    fn try_fold<A, B, F>(
+        self,
+        mut acc: A,
+        mut f: F,
+    ) -> impl Future<Output = ControlFlow<(Self, B), A>>
+    where
+        F: FnMut(A, Self::Item) -> ControlFlow<B, A>,
+    {
+        async move {
+            use ControlFlow::{Break, Continue};
+
+            let mut it = self;
+
+            while let Some((next, x)) = it.into_next().await {
+                match f(acc, x) {
+                    Continue(a) => {
+                        it = next;
+                        acc = a;
+                    }
+                    Break(b) => {
+                        return Break((next, b));
+                    }
+                }
+            }
+
+            Continue(acc)
+        }
+    }
+}
+
+impl<I> LocalAsyncFiniteMoveIteratorExt for I where I: LocalAsyncFiniteMoveIterator {}
+
+impl<I> LocalAsyncFiniteMoveIterator for I
+where
+    I: Iterator + Sized,
+{
+    type Item = I::Item;
+
+    async fn into_next(mut self) -> Option<(Self, Self::Item)> {
+        self.next().map(|item| (self, item))
+    }
+}
@@ -0,0 +1,562 @@
+//! The [LocalAsyncTerminalMoveIterator] and [LocalAsyncTerminalMoveIteratorExt] traits.
+use crate::adapters::{
+    LatmiAndThenTerminal, LatmiAsBlocking, LatmiAsEndlessOr, LatmiAsFiniteDiscarding,
+    LatmiAsFiniteWithSlot, LatmiBufferUnordered, LatmiBuffered, LatmiContinueWith,
+    LatmiFilterAsync, LatmiFilterMapAsync, LatmiMapErr, LatmiMapTerminal, LatmiPeekable, LatmiScan,
+    LatmiThen, LatmiTryThen, LatmiZip, LatmiZipLongest, TerminalSlot,
+};
+use crate::blocking::{BlockOn, ThreadParkExecutor};
+use crate::futpool::FuturePool;
+use crate::FromTerminalMoveIterator;
+use either::Either;
+use std::future::{poll_fn, Future};
+use std::ops::ControlFlow;
+use std::pin::pin;
+use std::task::Poll;
+
+/// Produce a sequence of 0 or more `Item` values asynchronously, then produce a `Terminal` value, using move semantics.
+///
+/// This is the counterpart of [AsyncTerminalMoveIterator](crate::AsyncTerminalMoveIterator)
+/// without `Send` bounds, for iterators holding `Rc`, `RefCell`, or other `!Send` state on a
+/// single-threaded executor such as a `tokio::task::LocalSet`. Any [Iterator] is a
+/// `LocalAsyncTerminalMoveIterator` with `Terminal = ()`, and any `AsyncTerminalMoveIterator`
+/// converts with [into_local](crate::AsyncTerminalMoveIteratorExt::into_local).
+///
+/// # Example
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::LocalAsyncTerminalMoveIteratorExt;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let log = Rc::new(RefCell::new(vec![]));
+///
+/// let l = log.clone();
+/// LocalAsyncTerminalMoveIteratorExt::for_each_async(0..3, move |x| {
+///     let l = l.clone();
+///     async move { l.borrow_mut().push(x) }
+/// })
+/// .await;
+///
+/// assert_eq!(*log.borrow(), vec![0, 1, 2]);
+/// # });
+/// ```
+pub trait LocalAsyncTerminalMoveIterator: Sized {
+    type Item;
+    type Terminal;
+
+    /// Iteration is async, moves `self`, and produces either a `(Self, Self::Item)` pair, or the
+    /// `Self::Terminal` value.
+    ///
+    /// Implementations may write this as an `async fn`, which compiles to an unboxed state
+    /// machine:
+    ///
+    /// ```
+    /// # use moveiter::LocalAsyncTerminalMoveIterator;
+    /// use either::Either::{self, Left, Right};
+    ///
+    /// struct Countdown(u32);
+    ///
+    /// impl LocalAsyncTerminalMoveIterator for Countdown {
+    ///     type Item = u32;
+    ///     type Terminal = &'static str;
+    ///
+    ///     async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+    ///         match self.0.checked_sub(1) {
+    ///             Some(n) => Left((Countdown(n), n)),
+    ///             None => Right("liftoff"),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn into_next(self) -> impl Future<Output = Either<(Self, Self::Item), Self::Terminal>>;
+}
+
+/// The adapters and consumers of a [LocalAsyncTerminalMoveIterator], implemented for every
+/// [LocalAsyncTerminalMoveIterator].
+pub trait LocalAsyncTerminalMoveIteratorExt: LocalAsyncTerminalMoveIterator {
+    /// Adapt `self` into a [TerminalMoveIterator](crate::TerminalMoveIterator) which blocks the current
+    /// thread on each step with a [ThreadParkExecutor].
+    fn into_blocking(self) -> LatmiAsBlocking<Self, ThreadParkExecutor> {
+        LatmiAsBlocking(self, ThreadParkExecutor)
+    }
+
+    /// Adapt `self` into a [TerminalMoveIterator](crate::TerminalMoveIterator) which blocks the current
+    /// thread on each step with `executor`.
+    fn into_blocking_with<B>(self, executor: B) -> LatmiAsBlocking<Self, B>
+    where
+        B: BlockOn,
+    {
+        LatmiAsBlocking(self, executor)
+    }
+
+    /// Adapt `self` into a [LocalAsyncFiniteMoveIterator](crate::LocalAsyncFiniteMoveIterator), dropping the
+    /// `Terminal`.
+    fn into_finite_discarding_terminal(self) -> LatmiAsFiniteDiscarding<Self> {
+        LatmiAsFiniteDiscarding(self)
+    }
+
+    /// Adapt `self` into a [LocalAsyncFiniteMoveIterator](crate::LocalAsyncFiniteMoveIterator) paired with a
+    /// [TerminalSlot], which receives the `Terminal` once iteration ends.
+    fn into_finite_with_terminal_slot(
+        self,
+    ) -> (LatmiAsFiniteWithSlot<Self>, TerminalSlot<Self::Terminal>) {
+        let slot = TerminalSlot::new();
+        (LatmiAsFiniteWithSlot(self, slot.clone()), slot)
+    }
+
+    /// Adapt `self` into a [LocalAsyncEndlessMoveIterator](crate::LocalAsyncEndlessMoveIterator) which produces
+    /// each `Item`, then produces `fallback(&terminal)` forever after.
+    fn into_endless_or<F>(self, fallback: F) -> LatmiAsEndlessOr<Self, F>
+    where
+        F: FnMut(&Self::Terminal) -> Self::Item,
+    {
+        LatmiAsEndlessOr(Either::Left(self), fallback)
+    }
+
+    /// Transform the `Terminal` with `f`, passing each `Item` through.
+    fn map_terminal<R, F>(self, f: F) -> LatmiMapTerminal<Self, F>
+    where
+        F: FnOnce(Self::Terminal) -> R,
+    {
+        LatmiMapTerminal(self, f)
+    }
+
+    /// Transform the error of a [Result] `Terminal` with `f`, passing each `Item` through.
+    fn map_err<T, E, E2, F>(self, f: F) -> LatmiMapErr<Self, F>
+    where
+        Self: LocalAsyncTerminalMoveIterator<Terminal = Result<T, E>>,
+        F: FnOnce(E) -> E2,
+    {
+        LatmiMapErr(self, f)
+    }
+
+    /// Convert the error of a [Result] `Terminal` with [From], as the `?` operator does.
+    fn err_into<T, E, E2>(self) -> LatmiMapErr<Self, fn(E) -> E2>
+    where
+        Self: LocalAsyncTerminalMoveIterator<Terminal = Result<T, E>>,
+        E2: From<E>,
+    {
+        LatmiMapErr(self, E2::from)
+    }
+
+    /// Call `f` on each `Terminal`, which either continues iteration with a fresh `Self` value
+    /// (`Left`), or produces the final `Terminal` (`Right`).
+    fn and_then_terminal<R, F>(self, f: F) -> LatmiAndThenTerminal<Self, F>
+    where
+        F: FnMut(Self::Terminal) -> Either<Self, R>,
+    {
+        LatmiAndThenTerminal(self, f)
+    }
+
+    /// Produce each `Item` of `self`, then pass `Terminal` to `f` to produce a second iterator,
+    /// then produce its `Item`s and `Terminal`.
+    fn continue_with<J, F>(self, f: F) -> LatmiContinueWith<Self, F, J>
+    where
+        F: FnOnce(Self::Terminal) -> J,
+        J: LocalAsyncTerminalMoveIterator<Item = Self::Item>,
+    {
+        LatmiContinueWith(Either::Left((self, f)))
+    }
+
+    /// Produce pairs of `Item`s from `self` and `other` until either terminates.
+    ///
+    /// The [ZipTerminal](crate::adapters::ZipTerminal) identifies which side ended first, and
+    /// carries the remainder of the other side, so no `Item`s are lost.
+    fn zip<J>(self, other: J) -> LatmiZip<Self, J>
+    where
+        J: LocalAsyncTerminalMoveIterator,
+    {
+        LatmiZip(self, other)
+    }
+
+    /// Produce [EitherOrBoth](crate::adapters::EitherOrBoth) `Item`s from `self` and `other`
+    /// until both terminate, then produce both `Terminal`s.
+    fn zip_longest<J>(self, other: J) -> LatmiZipLongest<Self, J>
+    where
+        J: LocalAsyncTerminalMoveIterator,
+    {
+        LatmiZipLongest::new(self, other)
+    }
+
+    /// Adapt `self` to support lookahead of the next `Item` or the `Terminal` with
+    /// [LatmiPeekable::into_peek] and [LatmiPeekable::peeked], and to put `Item`s back in front with
+    /// [LatmiPeekable::push_back].
+    fn peekable(self) -> LatmiPeekable<Self> {
+        LatmiPeekable::new(self)
+    }
+
+    /// Thread a `state` through the async `f` on each `Item`, producing the `Some` results of `f`.
+    ///
+    /// Because `f` is async, it takes the state by value and returns it along with its optional
+    /// result. Returning `None` skips an `Item` rather than ending iteration, and the final state
+    /// is kept in the resulting `(state, Terminal)` pair.
+    fn scan<S, B, F, Fut>(self, init: S, f: F) -> LatmiScan<Self, S, F>
+    where
+        F: FnMut(S, Self::Item) -> Fut,
+        Fut: Future<Output = (S, Option<B>)>,
+    {
+        LatmiScan(self, init, f)
+    }
+
+    /// Transform each `Item` with an async closure, awaiting each result in turn.
+    fn then<B, F, Fut>(self, f: F) -> LatmiThen<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        LatmiThen(self, f)
+    }
+
+    /// Keep only the `Item`s for which the async predicate resolves to `true`.
+    fn filter_async<P, Fut>(self, pred: P) -> LatmiFilterAsync<Self, P>
+    where
+        P: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        LatmiFilterAsync(self, pred)
+    }
+
+    /// Transform each `Item` with an async closure, keeping only the `Some` results.
+    fn filter_map_async<B, F, Fut>(self, f: F) -> LatmiFilterMapAsync<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = Option<B>>,
+    {
+        LatmiFilterMapAsync(self, f)
+    }
+
+    /// Transform each `Item` with a fallible async closure, ending with the first `Err`.
+    fn try_then<B, E, F, Fut>(self, f: F) -> LatmiTryThen<Self, F>
+    where
+        Self: LocalAsyncTerminalMoveIterator<Terminal = Result<(), E>>,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = Result<B, E>>,
+    {
+        LatmiTryThen(self, f)
+    }
+
+    /// Await the `Item` futures with up to `limit` in flight at once, yielding their outputs in
+    /// the original order.
+    ///
+    /// The source is only advanced while fewer than `limit` futures are in flight, and its
+    /// `Terminal` is produced only after every in-flight future has finished. The in-flight
+    /// futures keep making progress while the source is pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    fn buffered<'a>(self, limit: usize) -> LatmiBuffered<'a, Self>
+    where
+        Self: 'a,
+        Self::Item: Future,
+    {
+        LatmiBuffered::new(self, limit)
+    }
+
+    /// Await the `Item` futures with up to `limit` in flight at once, yielding their outputs as
+    /// they finish.
+    ///
+    /// The source is only advanced while fewer than `limit` futures are in flight, and its
+    /// `Terminal` is produced only after every in-flight future has finished. The in-flight
+    /// futures keep making progress while the source is pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    fn buffer_unordered<'a>(self, limit: usize) -> LatmiBufferUnordered<'a, Self>
+    where
+        Self: 'a,
+        Self::Item: Future,
+    {
+        LatmiBufferUnordered::new(self, limit)
+    }
+
+    /// Call a closure on each `Item`, then return the `Terminal`.
+    fn for_each<F>(self, mut f: F) -> impl Future<Output = Self::Terminal>
+    where
+        F: FnMut(Self::Item),
+    {
+        async move {
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => {
+                        it = next;
+                        f(x);
+                    }
+                    Right(term) => {
+                        return term;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Call an async closure on each `Item`, awaiting each result in turn, then return the
+    /// `Terminal`.
+    fn for_each_async<F, Fut>(self, mut f: F) -> impl Future<Output = Self::Terminal>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        async move {
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                let (next, x) = match it.into_next().await {
+                    Left(next) => next,
+                    Right(term) => {
+                        return term;
+                    }
+                };
+                it = next;
+                f(x).await;
+            }
+        }
+    }
+
+    /// Call an async closure on each `Item`, with up to `limit` of the resulting futures in
+    /// flight at once, then return the `Terminal` once they have all finished.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    fn for_each_concurrent<F, Fut>(
+        self,
+        limit: usize,
+        mut f: F,
+    ) -> impl Future<Output = Self::Terminal>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        async move {
+            use Either::*;
+
+            assert!(
+                limit > 0,
+                "for_each_concurrent requires a limit of at least 1"
+            );
+
+            let mut pool = FuturePool::new();
+            let mut step = pin!(self.into_next());
+
+            // Race the source against the pool, so that the in-flight futures keep making progress
+            // while the source is pending:
+            let term = loop {
+                let next = poll_fn(|cx| {
+                    while let Poll::Ready(Some(())) = pool.poll_next_unordered(cx) {}
+                    if pool.len() < limit {
+                        step.as_mut().poll(cx)
+                    } else {
+                        Poll::Pending
+                    }
+                })
+                .await;
+                match next {
+                    Left((next, x)) => {
+                        pool.push(f(x));
+                        step.set(next.into_next());
+                    }
+                    Right(term) => break term,
+                }
+            };
+
+            while pool.next_unordered().await.is_some() {}
+            term
+        }
+    }
+
+    /// Folds every `Item` into an accumulator with `fitem`, then integrates the final accumulator
+    /// value with `Terminal` in `fterm`.
+    fn fold<A, F, T, R>(self, mut acc: A, mut fitem: F, fterm: T) -> impl Future<Output = R>
+    where
+        F: FnMut(A, Self::Item) -> A,
+        T: FnOnce(A, Self::Terminal) -> R,
+    {
+        async move {
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => {
+                        it = next;
+                        acc = fitem(acc, x);
+                    }
+                    Right(term) => {
+                        return fterm(acc, term);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Folds every `Item` into an accumulator with the async `fitem`, then integrates the final
+    /// accumulator value with `Terminal` in `fterm`.
+    fn fold_async<A, F, Fut, T, R>(
+        self,
+        mut acc: A,
+        mut fitem: F,
+        fterm: T,
+    ) -> impl Future<Output = R>
+    where
+        F: FnMut(A, Self::Item) -> Fut,
+        Fut: Future<Output = A>,
+        T: FnOnce(A, Self::Terminal) -> R,
+    {
+        async move {
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                let (next, x) = match it.into_next().await {
+                    Left(next) => next,
+                    Right(term) => {
+                        return fterm(acc, term);
+                    }
+                };
+                it = next;
+                acc = fitem(acc, x).await;
+            }
+        }
+    }
+
+    /// Count the `Item`s, and return the count along with the `Terminal`.
+    fn count(self) -> impl Future<Output = (usize, Self::Terminal)> {
+        async move { self.fold(0, |n, _| n + 1, |n, term| (n, term)).await }
+    }
+
+    /// Return the last `Item`, if any, along with the `Terminal`.
+    fn last(self) -> impl Future<Output = (Option<Self::Item>, Self::Terminal)>
+where {
+        async move {
+            self.fold(None, |_, x| Some(x), |last, term| (last, term))
+                .await
+        }
+    }
+
+    /// Call a closure on each `Item` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value, otherwise
+    /// the `Terminal` is returned.
+    fn try_for_each<B, F>(
+        self,
+        mut f: F,
+    ) -> impl Future<Output = ControlFlow<(Self, B), Self::Terminal>>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<B>,
+    {
+        async move {
+            use ControlFlow::{Break, Continue};
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => match f(x) {
+                        Continue(()) => {
+                            it = next;
+                        }
+                        Break(b) => {
+                            return Break((next, b));
+                        }
+                    },
+                    Right(term) => {
+                        return Continue(term);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Folds `Item`s into an accumulator with `f` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value, otherwise
+    /// the final accumulator is returned with the `Terminal`.
+    fn try_fold<A, B, F>(
+        self,
+        mut acc: A,
+        mut f: F,
+    ) -> impl Future<Output = ControlFlow<(Self, B), (A, Self::Terminal)>>
+    where
+        F: FnMut(A, Self::Item) -> ControlFlow<B, A>,
+    {
+        async move {
+            use ControlFlow::{Break, Continue};
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => match f(acc, x) {
+                        Continue(a) => {
+                            it = next;
+                            acc = a;
+                        }
+                        Break(b) => {
+                            return Break((next, b));
+                        }
+                    },
+                    Right(term) => {
+                        return Continue((acc, term));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Collect every `Item` into `C`, and return it along with the `Terminal`.
+    fn collect<C>(self) -> impl Future<Output = (C, Self::Terminal)>
+    where
+        C: FromTerminalMoveIterator<Self::Item>,
+    {
+        async move {
+            use Either::*;
+
+            let mut collection = C::default();
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => {
+                        it = next;
+                        collection.extend(Some(x));
+                    }
+                    Right(term) => {
+                        return (collection, term);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<I> LocalAsyncTerminalMoveIteratorExt for I where I: LocalAsyncTerminalMoveIterator {}
+
+impl<I> LocalAsyncTerminalMoveIterator for I
+where
+    I: Iterator + Sized,
+{
+    type Item = I::Item;
+    type Terminal = ();
+
+    async fn into_next(mut self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        self.next()
+            .map(|item| Left((self, item)))
+            .unwrap_or(Right(()))
+    }
+}
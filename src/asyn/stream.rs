@@ -0,0 +1,49 @@
+//! Conversions from [futures::Stream].
+
+use crate::adapters::{StreamAsAfmi, TryStreamAsAtmi};
+use futures::Stream;
+
+/// Convert a [Stream] into an [AsyncFiniteMoveIterator](crate::AsyncFiniteMoveIterator).
+///
+/// # Example
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::AsyncFiniteMoveIteratorExt;
+///
+/// let it = moveiter::async_finite_move_iterator_from_stream(futures::stream::iter(1..4));
+///
+/// assert_eq!(it.fold(0, |a, x| a + x).await, 6);
+/// # });
+/// ```
+pub fn async_finite_move_iterator_from_stream<S>(stream: S) -> StreamAsAfmi<S>
+where
+    S: Stream + Unpin + Send,
+{
+    StreamAsAfmi(stream)
+}
+
+/// Convert a [Stream] with `Item = Result<T, E>` into an
+/// [AsyncTerminalMoveIterator](crate::AsyncTerminalMoveIterator) which terminates with
+/// `Result<(), E>` at the first `Err`.
+///
+/// # Example
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::AsyncTerminalMoveIteratorExt;
+///
+/// let stream = futures::stream::iter(vec![Ok(1), Ok(2), Err("x"), Ok(3)]);
+/// let it = moveiter::async_terminal_move_iterator_from_try_stream(stream);
+///
+/// let (v, term): (Vec<i32>, _) = it.collect().await;
+/// assert_eq!(v, vec![1, 2]);
+/// assert_eq!(term, Err("x"));
+/// # });
+/// ```
+pub fn async_terminal_move_iterator_from_try_stream<S, T, E>(stream: S) -> TryStreamAsAtmi<S>
+where
+    S: Stream<Item = Result<T, E>> + Unpin + Send,
+{
+    TryStreamAsAtmi(stream)
+}
@@ -1,6 +1,32 @@
-//! The [AsyncTerminalMoveIterator] trait.
-use async_trait::async_trait;
+//! The [AsyncTerminalMoveIterator] and [AsyncTerminalMoveIteratorExt] traits and their
+//! constructors.
+
+mod constructors;
+
+#[cfg(feature = "tokio")]
+use crate::adapters::AtmiWithTimeout;
+use crate::adapters::{
+    AtmiAndThenTerminal, AtmiAsBlocking, AtmiAsEndlessOr, AtmiAsFiniteDiscarding,
+    AtmiAsFiniteWithSlot, AtmiAsLocal, AtmiBufferUnordered, AtmiBuffered, AtmiContinueWith,
+    AtmiFilterAsync, AtmiFilterMapAsync, AtmiMapErr, AtmiMapTerminal, AtmiPeekable, AtmiScan,
+    AtmiThen, AtmiTryThen, AtmiZip, AtmiZipLongest, TerminalSlot,
+};
+#[cfg(feature = "futures")]
+use crate::adapters::{AtmiStream, AtmiTryStream, ForwardError};
+use crate::blocking::{BlockOn, ThreadParkExecutor};
+use crate::futpool::FuturePool;
+use crate::FromTerminalMoveIterator;
 use either::Either;
+#[cfg(feature = "futures")]
+use futures::{Sink, SinkExt};
+use std::future::{poll_fn, Future};
+use std::ops::ControlFlow;
+use std::pin::pin;
+use std::task::Poll;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+
+pub use self::constructors::unfold;
 
 /// Produce a sequence of 0 or more `Item` values asynchronously, then produce a `Terminal` value, using move semantics.
 ///
@@ -89,8 +115,8 @@ use either::Either;
 /// note: this function takes ownership of the receiver `self`, which moves `it`
 ///    --> /home/user/hack/moveiter/src/asyn/terminal.rs:142:24
 ///     |
-/// 142 |     async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal>;
-///     |                        ^^^^
+/// 142 |     fn into_next(self) -> impl Future<Output = Either<(Self, Self::Item), Self::Terminal>> + Send;
+///     |                  ^^^^
 /// help: consider further restricting this bound
 ///     |
 /// 6   |   where I: AsyncTerminalMoveIterator<Terminal = R> + Copy,
@@ -129,7 +155,6 @@ use either::Either;
 ///     }
 /// }
 /// ```
-#[async_trait]
 pub trait AsyncTerminalMoveIterator: Sized + Send {
     type Item;
     type Terminal;
@@ -137,21 +162,956 @@ pub trait AsyncTerminalMoveIterator: Sized + Send {
     /// Iteration is async, moves `self`, and produces either a `(Self, Self::Item)` pair, or the
     /// `Self::Terminal` value.
     ///
-    /// The simplified interface via [async_trait](::async_trait) looks like:
+    /// Implementations may write this as an `async fn`, which compiles to an unboxed state
+    /// machine:
+    ///
+    /// ```
+    /// # use moveiter::AsyncTerminalMoveIterator;
+    /// use either::Either::{self, Left, Right};
+    ///
+    /// struct Countdown(u32);
     ///
-    /// ```no_run
-    /// # use either::Either;
-    /// # #[async_trait::async_trait]
-    /// # trait T: Sized {
-    /// # type Item;
-    /// # type Terminal;
-    /// async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal>;
-    /// # }
+    /// impl AsyncTerminalMoveIterator for Countdown {
+    ///     type Item = u32;
+    ///     type Terminal = &'static str;
+    ///
+    ///     async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+    ///         match self.0.checked_sub(1) {
+    ///             Some(n) => Left((Countdown(n), n)),
+    ///             None => Right("liftoff"),
+    ///         }
+    ///     }
+    /// }
     /// ```
-    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal>;
+    fn into_next(self) -> impl Future<Output = Either<(Self, Self::Item), Self::Terminal>> + Send;
 }
 
-#[async_trait]
+/// The adapters and consumers of an [AsyncTerminalMoveIterator], implemented for every
+/// [AsyncTerminalMoveIterator].
+///
+/// An [Iterator] value, whose own methods share several of these names, can use them after
+/// [into_async](crate::TerminalMoveIterator::into_async).
+pub trait AsyncTerminalMoveIteratorExt: AsyncTerminalMoveIterator {
+    /// Adapt `self` into a [LocalAsyncTerminalMoveIterator](crate::LocalAsyncTerminalMoveIterator),
+    /// for use alongside `!Send` iterators.
+    fn into_local(self) -> AtmiAsLocal<Self> {
+        AtmiAsLocal(self)
+    }
+
+    /// Adapt `self` into a [TerminalMoveIterator](crate::TerminalMoveIterator) which blocks the current
+    /// thread on each step with a [ThreadParkExecutor].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("x")])
+    ///     .into_async()
+    ///     .then(|x| async move { x * 10 });
+    ///
+    /// let mut v = vec![];
+    /// let term = it.into_blocking().for_each(|x| v.push(x));
+    ///
+    /// assert_eq!(v, vec![10, 20]);
+    /// assert_eq!(term, Err("x"));
+    /// ```
+    fn into_blocking(self) -> AtmiAsBlocking<Self, ThreadParkExecutor> {
+        AtmiAsBlocking(self, ThreadParkExecutor)
+    }
+
+    /// Adapt `self` into a [TerminalMoveIterator](crate::TerminalMoveIterator) which blocks the current
+    /// thread on each step with `executor`.
+    fn into_blocking_with<B>(self, executor: B) -> AtmiAsBlocking<Self, B>
+    where
+        B: BlockOn,
+    {
+        AtmiAsBlocking(self, executor)
+    }
+
+    /// Adapt `self` into an [AsyncFiniteMoveIterator](crate::AsyncFiniteMoveIterator), dropping the
+    /// `Terminal`.
+    fn into_finite_discarding_terminal(self) -> AtmiAsFiniteDiscarding<Self> {
+        AtmiAsFiniteDiscarding(self)
+    }
+
+    /// Adapt `self` into an [AsyncFiniteMoveIterator](crate::AsyncFiniteMoveIterator) paired with a
+    /// [TerminalSlot], which receives the `Terminal` once iteration ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{
+    ///     AsyncFiniteMoveIteratorExt, AsyncTerminalMoveIteratorExt, TerminalMoveIterator,
+    /// };
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("bad")]);
+    ///
+    /// let (it, slot) = it.into_async().into_finite_with_terminal_slot();
+    /// let items: Vec<_> = it.collect().await;
+    ///
+    /// assert_eq!(items, vec![1, 2]);
+    /// assert_eq!(slot.take(), Some(Err("bad")));
+    /// # });
+    /// ```
+    fn into_finite_with_terminal_slot(
+        self,
+    ) -> (AtmiAsFiniteWithSlot<Self>, TerminalSlot<Self::Terminal>)
+    where
+        Self::Terminal: Send,
+    {
+        let slot = TerminalSlot::new();
+        (AtmiAsFiniteWithSlot(self, slot.clone()), slot)
+    }
+
+    /// Adapt `self` into an [AsyncEndlessMoveIterator](crate::AsyncEndlessMoveIterator) which produces
+    /// each `Item`, then produces `fallback(&terminal)` forever after.
+    fn into_endless_or<F>(self, fallback: F) -> AtmiAsEndlessOr<Self, F>
+    where
+        Self::Terminal: Send,
+        F: FnMut(&Self::Terminal) -> Self::Item + Send,
+    {
+        AtmiAsEndlessOr(Either::Left(self), fallback)
+    }
+
+    /// Transform the `Terminal` with `f`, passing each `Item` through.
+    fn map_terminal<R, F>(self, f: F) -> AtmiMapTerminal<Self, F>
+    where
+        F: FnOnce(Self::Terminal) -> R + Send,
+    {
+        AtmiMapTerminal(self, f)
+    }
+
+    /// Transform the error of a [Result] `Terminal` with `f`, passing each `Item` through.
+    fn map_err<T, E, E2, F>(self, f: F) -> AtmiMapErr<Self, F>
+    where
+        Self: AsyncTerminalMoveIterator<Terminal = Result<T, E>>,
+        F: FnOnce(E) -> E2 + Send,
+    {
+        AtmiMapErr(self, f)
+    }
+
+    /// Convert the error of a [Result] `Terminal` with [From], as the `?` operator does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{
+    ///     AsyncTerminalMoveIterator, AsyncTerminalMoveIteratorExt, TerminalMoveIterator,
+    /// };
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Err::<i32, u8>(7)]);
+    ///
+    /// let term: Result<(), u32> = it.into_async().err_into().into_next().await.right().unwrap();
+    /// assert_eq!(term, Err(7));
+    /// # });
+    /// ```
+    fn err_into<T, E, E2>(self) -> AtmiMapErr<Self, fn(E) -> E2>
+    where
+        Self: AsyncTerminalMoveIterator<Terminal = Result<T, E>>,
+        E2: From<E>,
+    {
+        AtmiMapErr(self, E2::from)
+    }
+
+    /// Call `f` on each `Terminal`, which either continues iteration with a fresh `Self` value
+    /// (`Left`), or produces the final `Terminal` (`Right`).
+    fn and_then_terminal<R, F>(self, f: F) -> AtmiAndThenTerminal<Self, F>
+    where
+        F: FnMut(Self::Terminal) -> Either<Self, R> + Send,
+    {
+        AtmiAndThenTerminal(self, f)
+    }
+
+    /// Produce each `Item` of `self`, then pass `Terminal` to `f` to produce a second iterator,
+    /// then produce its `Item`s and `Terminal`.
+    fn continue_with<J, F>(self, f: F) -> AtmiContinueWith<Self, F, J>
+    where
+        F: FnOnce(Self::Terminal) -> J + Send,
+        J: AsyncTerminalMoveIterator<Item = Self::Item>,
+    {
+        AtmiContinueWith(Either::Left((self, f)))
+    }
+
+    /// Produce pairs of `Item`s from `self` and `other` until either terminates.
+    ///
+    /// The [ZipTerminal](crate::adapters::ZipTerminal) identifies which side ended first, and
+    /// carries the remainder of the other side, so no `Item`s are lost.
+    fn zip<J>(self, other: J) -> AtmiZip<Self, J>
+    where
+        J: AsyncTerminalMoveIterator,
+    {
+        AtmiZip(self, other)
+    }
+
+    /// Produce [EitherOrBoth](crate::adapters::EitherOrBoth) `Item`s from `self` and `other`
+    /// until both terminate, then produce both `Terminal`s.
+    fn zip_longest<J>(self, other: J) -> AtmiZipLongest<Self, J>
+    where
+        J: AsyncTerminalMoveIterator,
+    {
+        AtmiZipLongest::new(self, other)
+    }
+
+    /// Adapt `self` to support lookahead of the next `Item` or the `Terminal` with
+    /// [AtmiPeekable::into_peek] and [AtmiPeekable::peeked], and to put `Item`s back in front with
+    /// [AtmiPeekable::push_back].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use either::Either::{Left, Right};
+    /// use moveiter::{
+    ///     AsyncTerminalMoveIterator, AsyncTerminalMoveIteratorExt, TerminalMoveIterator,
+    /// };
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Err("eof")])
+    ///     .into_async()
+    ///     .peekable();
+    ///
+    /// let it = it.into_peek().await;
+    /// assert_eq!(it.peeked(), Some(Left(&1)));
+    ///
+    /// let (it, x) = it.into_next().await.left().unwrap();
+    /// assert_eq!(x, 1);
+    ///
+    /// // Check for the end of input without losing the terminal:
+    /// let it = it.into_peek().await;
+    /// assert_eq!(it.peeked(), Some(Right(&Err("eof"))));
+    /// assert_eq!(it.into_next().await.right(), Some(Err("eof")));
+    /// # });
+    /// ```
+    fn peekable(self) -> AtmiPeekable<Self> {
+        AtmiPeekable::new(self)
+    }
+
+    /// Thread a `state` through the async `f` on each `Item`, producing the `Some` results of `f`.
+    ///
+    /// Because `f` is async, it takes the state by value and returns it along with its optional
+    /// result. Returning `None` skips an `Item` rather than ending iteration, and the final state
+    /// is kept in the resulting `(state, Terminal)` pair.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::AsyncTerminalMoveIteratorExt;
+    ///
+    /// let it = AsyncTerminalMoveIteratorExt::scan(vec![1, 2, 3].into_iter(), 0, |total, x| async move {
+    ///     (total + x, Some(total + x))
+    /// });
+    ///
+    /// let (totals, (total, ())): (Vec<i32>, _) = it.collect().await;
+    /// assert_eq!(totals, vec![1, 3, 6]);
+    /// assert_eq!(total, 6);
+    /// # });
+    /// ```
+    fn scan<S, B, F, Fut>(self, init: S, f: F) -> AtmiScan<Self, S, F>
+    where
+        S: Send,
+        F: FnMut(S, Self::Item) -> Fut + Send,
+        Fut: Future<Output = (S, Option<B>)> + Send,
+    {
+        AtmiScan(self, init, f)
+    }
+
+    /// Transform each `Item` with an async closure, awaiting each result in turn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("x")]);
+    ///
+    /// let (v, term): (Vec<i32>, _) = it
+    ///     .into_async()
+    ///     .then(|x| async move { x * 10 })
+    ///     .filter_async(|&x| async move { x > 10 })
+    ///     .collect()
+    ///     .await;
+    ///
+    /// assert_eq!(v, vec![20]);
+    /// assert_eq!(term, Err("x"));
+    /// # });
+    /// ```
+    fn then<B, F, Fut>(self, f: F) -> AtmiThen<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = B> + Send,
+    {
+        AtmiThen(self, f)
+    }
+
+    /// Keep only the `Item`s for which the async predicate resolves to `true`.
+    fn filter_async<P, Fut>(self, pred: P) -> AtmiFilterAsync<Self, P>
+    where
+        Self::Item: Send,
+        P: FnMut(&Self::Item) -> Fut + Send,
+        Fut: Future<Output = bool> + Send,
+    {
+        AtmiFilterAsync(self, pred)
+    }
+
+    /// Transform each `Item` with an async closure, keeping only the `Some` results.
+    fn filter_map_async<B, F, Fut>(self, f: F) -> AtmiFilterMapAsync<Self, F>
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = Option<B>> + Send,
+    {
+        AtmiFilterMapAsync(self, f)
+    }
+
+    /// Transform each `Item` with a fallible async closure, ending with the first `Err`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Ok(3)]);
+    ///
+    /// let (v, term): (Vec<i32>, _) = it
+    ///     .into_async()
+    ///     .try_then(|x| async move { if x < 3 { Ok(x * 10) } else { Err("too big") } })
+    ///     .collect()
+    ///     .await;
+    ///
+    /// assert_eq!(v, vec![10, 20]);
+    /// assert_eq!(term, Err("too big"));
+    /// # });
+    /// ```
+    fn try_then<B, E, F, Fut>(self, f: F) -> AtmiTryThen<Self, F>
+    where
+        Self: AsyncTerminalMoveIterator<Terminal = Result<(), E>>,
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = Result<B, E>> + Send,
+    {
+        AtmiTryThen(self, f)
+    }
+
+    /// Await the `Item` futures with up to `limit` in flight at once, yielding their outputs in
+    /// the original order.
+    ///
+    /// The source is only advanced while fewer than `limit` futures are in flight, and its
+    /// `Terminal` is produced only after every in-flight future has finished. The in-flight
+    /// futures keep making progress while the source is pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("x")]);
+    ///
+    /// let (v, term): (Vec<i32>, _) = it
+    ///     .into_async()
+    ///     .then(|x| async move { async move { x * 10 } })
+    ///     .buffered(2)
+    ///     .collect()
+    ///     .await;
+    ///
+    /// assert_eq!(v, vec![10, 20]);
+    /// assert_eq!(term, Err("x"));
+    /// # });
+    /// ```
+    fn buffered<'a>(self, limit: usize) -> AtmiBuffered<'a, Self>
+    where
+        Self: 'a,
+        Self::Item: Future,
+    {
+        AtmiBuffered::new(self, limit)
+    }
+
+    /// Await the `Item` futures with up to `limit` in flight at once, yielding their outputs as
+    /// they finish.
+    ///
+    /// The source is only advanced while fewer than `limit` futures are in flight, and its
+    /// `Terminal` is produced only after every in-flight future has finished. The in-flight
+    /// futures keep making progress while the source is pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Ok(3)]);
+    ///
+    /// // Smaller items take longer to finish.
+    /// let (v, term): (Vec<i32>, Result<(), ()>) = it
+    ///     .into_async()
+    ///     .then(|x| async move {
+    ///         async move {
+    ///             for _ in x..3 {
+    ///                 tokio::task::yield_now().await;
+    ///             }
+    ///             x
+    ///         }
+    ///     })
+    ///     .buffer_unordered(3)
+    ///     .collect()
+    ///     .await;
+    ///
+    /// assert_eq!(v, vec![3, 2, 1]);
+    /// assert_eq!(term, Ok(()));
+    /// # });
+    /// ```
+    fn buffer_unordered<'a>(self, limit: usize) -> AtmiBufferUnordered<'a, Self>
+    where
+        Self: 'a,
+        Self::Item: Future,
+    {
+        AtmiBufferUnordered::new(self, limit)
+    }
+
+    /// Limit each step to `duration`, handing back the iterator if a step takes too long.
+    ///
+    /// The resulting `Terminal` is `Ok` when `self` ends naturally,
+    /// [TimeoutError::Inner](crate::adapters::TimeoutError::Inner) when `self` ends with an error,
+    /// and [TimeoutError::TimedOut](crate::adapters::TimeoutError::TimedOut) when a step exceeds
+    /// `duration`. A timed-out iterator keeps its in-progress step, so calling `into_next` on it
+    /// again resumes that step rather than losing it. Timers require a tokio runtime with time
+    /// enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// use moveiter::adapters::TimeoutError;
+    /// use moveiter::{
+    ///     AsyncTerminalMoveIterator, AsyncTerminalMoveIteratorExt, TerminalMoveIterator,
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(vec![Ok(0), Ok(60)])
+    ///     .into_async()
+    ///     .then(|ms| async move {
+    ///         tokio::time::sleep(Duration::from_millis(ms)).await;
+    ///         ms
+    ///     })
+    ///     .with_timeout(Duration::from_millis(20));
+    ///
+    /// let (it, x) = it.into_next().await.left().unwrap();
+    /// assert_eq!(x, 0);
+    ///
+    /// let mut it = match it.into_next().await.right().unwrap() {
+    ///     Err(TimeoutError::TimedOut(it)) => it,
+    ///     _ => unreachable!(),
+    /// };
+    ///
+    /// // Give the slow step more time to finish, without restarting it:
+    /// it.set_duration(Duration::from_secs(10));
+    /// let (it, x) = it.into_next().await.left().unwrap();
+    /// assert_eq!(x, 60);
+    ///
+    /// assert!(matches!(it.into_next().await.right(), Some(Ok(()))));
+    /// # });
+    /// ```
+    #[cfg(feature = "tokio")]
+    fn with_timeout<'a, T, E>(self, duration: Duration) -> AtmiWithTimeout<'a, Self>
+    where
+        Self: AsyncTerminalMoveIterator<Terminal = Result<T, E>> + 'a,
+    {
+        AtmiWithTimeout::new(self, duration)
+    }
+
+    /// Convert `self` into a [Stream](futures::Stream) of `Item`s, paired with a [TerminalSlot]
+    /// which receives the `Terminal` once the stream ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use futures::StreamExt;
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("x")]);
+    /// let (stream, slot) = it.into_async().into_stream();
+    ///
+    /// assert_eq!(slot.take(), None);
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2]);
+    /// assert_eq!(slot.take(), Some(Err("x")));
+    /// # });
+    /// ```
+    #[cfg(feature = "futures")]
+    fn into_stream<'a>(self) -> (AtmiStream<'a, Self>, TerminalSlot<Self::Terminal>)
+    where
+        Self: 'a,
+    {
+        AtmiStream::new(self)
+    }
+
+    /// Convert `self` into a [Stream](futures::Stream) of `Ok` items, followed by a final `Err`
+    /// item if `self` terminates with one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use futures::TryStreamExt;
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("x")]);
+    /// let stream = it.into_async().into_try_stream();
+    ///
+    /// assert_eq!(stream.try_collect::<Vec<_>>().await, Err("x"));
+    /// # });
+    /// ```
+    #[cfg(feature = "futures")]
+    fn into_try_stream<'a, E>(self) -> AtmiTryStream<'a, Self>
+    where
+        Self: AsyncTerminalMoveIterator<Terminal = Result<(), E>> + 'a,
+    {
+        AtmiTryStream::new(self)
+    }
+
+    /// Call a closure on each `Item`, then return the `Terminal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("x")]);
+    ///
+    /// let mut sum = 0;
+    /// let term = it.into_async().for_each(|x| sum += x).await;
+    ///
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(term, Err("x"));
+    /// # });
+    /// ```
+    fn for_each<F>(self, mut f: F) -> impl Future<Output = Self::Terminal> + Send
+    where
+        F: FnMut(Self::Item) + Send,
+    {
+        async move {
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => {
+                        it = next;
+                        f(x);
+                    }
+                    Right(term) => {
+                        return term;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Call an async closure on each `Item`, awaiting each result in turn, then return the
+    /// `Terminal`.
+    fn for_each_async<F, Fut>(self, mut f: F) -> impl Future<Output = Self::Terminal> + Send
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+    {
+        async move {
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                let (next, x) = match it.into_next().await {
+                    Left(next) => next,
+                    Right(term) => {
+                        return term;
+                    }
+                };
+                it = next;
+                f(x).await;
+            }
+        }
+    }
+
+    /// Call an async closure on each `Item`, with up to `limit` of the resulting futures in
+    /// flight at once, then return the `Terminal` once they have all finished.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Ok(3), Err("x")]);
+    ///
+    /// let (active, peak, sum) = (AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0));
+    /// let term = it
+    ///     .into_async()
+    ///     .for_each_concurrent(2, |x| {
+    ///         let (active, peak, sum) = (&active, &peak, &sum);
+    ///         async move {
+    ///             peak.fetch_max(active.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+    ///             for _ in 0..3 {
+    ///                 tokio::task::yield_now().await;
+    ///             }
+    ///             sum.fetch_add(x, Ordering::SeqCst);
+    ///             active.fetch_sub(1, Ordering::SeqCst);
+    ///         }
+    ///     })
+    ///     .await;
+    ///
+    /// assert_eq!(term, Err("x"));
+    /// assert_eq!(sum.into_inner(), 6);
+    /// assert_eq!(peak.into_inner(), 2);
+    /// # });
+    /// ```
+    fn for_each_concurrent<F, Fut>(
+        self,
+        limit: usize,
+        mut f: F,
+    ) -> impl Future<Output = Self::Terminal> + Send
+    where
+        F: FnMut(Self::Item) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+        Self::Terminal: Send,
+    {
+        async move {
+            use Either::*;
+
+            assert!(
+                limit > 0,
+                "for_each_concurrent requires a limit of at least 1"
+            );
+
+            let mut pool = FuturePool::new();
+            let mut step = pin!(self.into_next());
+
+            // Race the source against the pool, so that the in-flight futures keep making progress
+            // while the source is pending:
+            let term = loop {
+                let next = poll_fn(|cx| {
+                    while let Poll::Ready(Some(())) = pool.poll_next_unordered(cx) {}
+                    if pool.len() < limit {
+                        step.as_mut().poll(cx)
+                    } else {
+                        Poll::Pending
+                    }
+                })
+                .await;
+                match next {
+                    Left((next, x)) => {
+                        pool.push(f(x));
+                        step.set(next.into_next());
+                    }
+                    Right(term) => break term,
+                }
+            };
+
+            while pool.next_unordered().await.is_some() {}
+            term
+        }
+    }
+
+    /// Send each `Item` into `sink`, then close it and return the `Terminal`.
+    ///
+    /// The sink is flushed after each `Item`, before awaiting the next one, so a buffering sink
+    /// delivers items while the source is still producing. If `sink` rejects an `Item`, the
+    /// remainder of `self` is returned in [ForwardError::Send].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("x")]);
+    ///
+    /// let mut v = vec![];
+    /// let res = it.into_async().forward(&mut v).await;
+    ///
+    /// assert_eq!(v, vec![1, 2]);
+    /// assert!(matches!(res, Ok(Err("x"))));
+    /// # });
+    /// ```
+    #[cfg(feature = "futures")]
+    fn forward<S>(
+        self,
+        mut sink: S,
+    ) -> impl Future<Output = Result<Self::Terminal, ForwardError<Self, Self::Terminal, S::Error>>> + Send
+    where
+        S: Sink<Self::Item> + Unpin + Send,
+        Self::Item: Send,
+        Self::Terminal: Send,
+    {
+        async move {
+            use Either::*;
+
+            let mut it = self;
+
+            let term = loop {
+                let (next, x) = match it.into_next().await {
+                    Left(next) => next,
+                    Right(term) => break term,
+                };
+                if let Err(e) = sink.send(x).await {
+                    return Err(ForwardError::Send(next, e));
+                }
+                it = next;
+            };
+
+            match sink.close().await {
+                Ok(()) => Ok(term),
+                Err(e) => Err(ForwardError::Close(term, e)),
+            }
+        }
+    }
+
+    /// Folds every `Item` into an accumulator with `fitem`, then integrates the final accumulator
+    /// value with `Terminal` in `fterm`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use moveiter::{AsyncTerminalMoveIterator, AsyncTerminalMoveIteratorExt};
+    /// # type Error = ();
+    /// async fn read_inputs_and_sum<I>(it: I) -> Result<i32, Error>
+    ///   where I: AsyncTerminalMoveIterator<Item = i32, Terminal = Result<(), Error>>,
+    /// {
+    ///     it.fold(0, |sum, x| sum + x, |sum, term| term.map(|()| sum)).await
+    /// }
+    /// ```
+    fn fold<A, F, T, R>(self, mut acc: A, mut fitem: F, fterm: T) -> impl Future<Output = R> + Send
+    where
+        A: Send,
+        F: FnMut(A, Self::Item) -> A + Send,
+        T: FnOnce(A, Self::Terminal) -> R + Send,
+    {
+        async move {
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => {
+                        it = next;
+                        acc = fitem(acc, x);
+                    }
+                    Right(term) => {
+                        return fterm(acc, term);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Folds every `Item` into an accumulator with the async `fitem`, then integrates the final
+    /// accumulator value with `Terminal` in `fterm`.
+    fn fold_async<A, F, Fut, T, R>(
+        self,
+        mut acc: A,
+        mut fitem: F,
+        fterm: T,
+    ) -> impl Future<Output = R> + Send
+    where
+        A: Send,
+        F: FnMut(A, Self::Item) -> Fut + Send,
+        Fut: Future<Output = A> + Send,
+        T: FnOnce(A, Self::Terminal) -> R + Send,
+    {
+        async move {
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                let (next, x) = match it.into_next().await {
+                    Left(next) => next,
+                    Right(term) => {
+                        return fterm(acc, term);
+                    }
+                };
+                it = next;
+                acc = fitem(acc, x).await;
+            }
+        }
+    }
+
+    /// Count the `Item`s, and return the count along with the `Terminal`.
+    fn count(self) -> impl Future<Output = (usize, Self::Terminal)> + Send {
+        async move { self.fold(0, |n, _| n + 1, |n, term| (n, term)).await }
+    }
+
+    /// Return the last `Item`, if any, along with the `Terminal`.
+    fn last(self) -> impl Future<Output = (Option<Self::Item>, Self::Terminal)> + Send
+    where
+        Self::Item: Send,
+    {
+        async move {
+            self.fold(None, |_, x| Some(x), |last, term| (last, term))
+                .await
+        }
+    }
+
+    /// Call a closure on each `Item` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value, otherwise
+    /// the `Terminal` is returned.
+    fn try_for_each<B, F>(
+        self,
+        mut f: F,
+    ) -> impl Future<Output = ControlFlow<(Self, B), Self::Terminal>> + Send
+    where
+        F: FnMut(Self::Item) -> ControlFlow<B> + Send,
+    {
+        async move {
+            use ControlFlow::{Break, Continue};
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => match f(x) {
+                        Continue(()) => {
+                            it = next;
+                        }
+                        Break(b) => {
+                            return Break((next, b));
+                        }
+                    },
+                    Right(term) => {
+                        return Continue(term);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Folds `Item`s into an accumulator with `f` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value, otherwise
+    /// the final accumulator is returned with the `Terminal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::AsyncTerminalMoveIteratorExt;
+    /// use std::ops::ControlFlow::{Break, Continue};
+    ///
+    /// // Sum until the total would exceed a budget:
+    /// let flow = AsyncTerminalMoveIteratorExt::try_fold(vec![3, 4, 5].into_iter(), 0, |sum, x| {
+    ///     if sum + x > 8 {
+    ///         Break(x)
+    ///     } else {
+    ///         Continue(sum + x)
+    ///     }
+    /// })
+    /// .await;
+    ///
+    /// match flow {
+    ///     Break((rest, x)) => {
+    ///         assert_eq!(x, 5);
+    ///         assert_eq!(rest.len(), 0);
+    ///     }
+    ///     Continue(_) => unreachable!(),
+    /// }
+    /// # });
+    /// ```
+    fn try_fold<A, B, F>(
+        self,
+        mut acc: A,
+        mut f: F,
+    ) -> impl Future<Output = ControlFlow<(Self, B), (A, Self::Terminal)>> + Send
+    where
+        A: Send,
+        F: FnMut(A, Self::Item) -> ControlFlow<B, A> + Send,
+    {
+        async move {
+            use ControlFlow::{Break, Continue};
+            use Either::*;
+
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => match f(acc, x) {
+                        Continue(a) => {
+                            it = next;
+                            acc = a;
+                        }
+                        Break(b) => {
+                            return Break((next, b));
+                        }
+                    },
+                    Right(term) => {
+                        return Continue((acc, term));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Collect every `Item` into `C`, and return it along with the `Terminal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// use moveiter::{AsyncTerminalMoveIteratorExt, TerminalMoveIterator};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("x")]);
+    ///
+    /// let (v, term): (Vec<i32>, _) = it.into_async().collect().await;
+    /// assert_eq!(v, vec![1, 2]);
+    /// assert_eq!(term, Err("x"));
+    /// # });
+    /// ```
+    fn collect<C>(self) -> impl Future<Output = (C, Self::Terminal)> + Send
+    where
+        C: FromTerminalMoveIterator<Self::Item> + Send,
+    {
+        async move {
+            use Either::*;
+
+            let mut collection = C::default();
+            let mut it = self;
+
+            loop {
+                match it.into_next().await {
+                    Left((next, x)) => {
+                        it = next;
+                        collection.extend(Some(x));
+                    }
+                    Right(term) => {
+                        return (collection, term);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<I> AsyncTerminalMoveIteratorExt for I where I: AsyncTerminalMoveIterator {}
+
 impl<I> AsyncTerminalMoveIterator for I
 where
     I: Iterator + Sized + Send,
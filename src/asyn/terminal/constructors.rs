@@ -0,0 +1,40 @@
+use crate::adapters::AtmiUnfold;
+use either::Either;
+use std::future::Future;
+
+/// Create an [AsyncTerminalMoveIterator](crate::AsyncTerminalMoveIterator) by threading `state`
+/// through `f`, whose future produces either the next `(state, Item)` pair, or the `Terminal`.
+///
+/// # Example
+///
+/// Poll a source until it reports completion:
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::AsyncTerminalMoveIteratorExt;
+/// use either::Either::{Left, Right};
+///
+/// async fn poll_status(attempt: u32) -> Option<&'static str> {
+///     if attempt < 3 { None } else { Some("ready") }
+/// }
+///
+/// let it = moveiter::asyn::terminal::unfold(0, |attempt| async move {
+///     match poll_status(attempt).await {
+///         None => Left((attempt + 1, attempt)),
+///         Some(status) => Right(status),
+///     }
+/// });
+///
+/// let (attempts, status): (Vec<u32>, _) = it.collect().await;
+/// assert_eq!(attempts, vec![0, 1, 2]);
+/// assert_eq!(status, "ready");
+/// # });
+/// ```
+pub fn unfold<S, F, Fut, B, T>(state: S, f: F) -> AtmiUnfold<S, F>
+where
+    S: Send,
+    F: FnMut(S) -> Fut + Send,
+    Fut: Future<Output = Either<(S, B), T>> + Send,
+{
+    AtmiUnfold(state, f)
+}
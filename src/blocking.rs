@@ -0,0 +1,100 @@
+//! Drive async move iterators to completion from synchronous code.
+//!
+//! The `into_blocking` methods on each async trait, such as
+//! [AsyncTerminalMoveIteratorExt::into_blocking](crate::AsyncTerminalMoveIteratorExt::into_blocking),
+//! use the built-in [ThreadParkExecutor]. The `into_blocking_with` methods accept any [BlockOn]
+//! impl, such as a `tokio::runtime::Handle` with the `tokio` feature.
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+/// Run a future to completion on the current thread.
+pub trait BlockOn {
+    /// Poll `future` until it completes and return its output, parking the calling thread
+    /// whenever it is pending.
+    ///
+    /// Calls must not be nested: calling `block_on` from within a future which is itself being
+    /// driven by `block_on` on the same thread may deadlock with [ThreadParkExecutor], because
+    /// the inner call can consume the wakeup meant for the outer one, and panics with a `tokio`
+    /// `Handle`.
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future;
+}
+
+/// A minimal [BlockOn] impl which polls on the current thread, parking it while the future is
+/// pending.
+///
+/// This provides no I/O or timer drivers, so futures which depend on a runtime such as `tokio`
+/// need that runtime's [BlockOn] impl instead.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ThreadParkExecutor;
+
+struct ThreadWaker(Thread);
+
+impl BlockOn for ThreadParkExecutor {
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(x) => return x,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+}
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Blocks with [Handle::block_on](::tokio::runtime::Handle::block_on), which panics if called
+/// from within an async context.
+///
+/// # Example
+///
+/// ```
+/// use moveiter::{AsyncFiniteMoveIteratorExt, FiniteMoveIterator};
+///
+/// let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+///
+/// let it = (0..2).into_async().chain(5..6).into_blocking_with(rt.handle().clone());
+/// let mut v = vec![];
+/// for x in it.into_iter() {
+///     v.push(x);
+/// }
+///
+/// assert_eq!(v, vec![0, 1, 5]);
+/// ```
+#[cfg(feature = "tokio")]
+impl BlockOn for ::tokio::runtime::Handle {
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        ::tokio::runtime::Handle::block_on(self, future)
+    }
+}
+
+impl<B> BlockOn for &B
+where
+    B: BlockOn + ?Sized,
+{
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        B::block_on(self, future)
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::VecDeque;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A bounded set of in-flight futures, polled together, which hands out results either in
+/// insertion order or in completion order.
+pub(crate) struct FuturePool<F>
+where
+    F: Future,
+{
+    slots: VecDeque<Slot<F>>,
+}
+
+enum Slot<F>
+where
+    F: Future,
+{
+    Pending(Pin<Box<F>>),
+    Done(F::Output),
+}
+
+impl<F> FuturePool<F>
+where
+    F: Future,
+{
+    pub(crate) fn new() -> Self {
+        FuturePool {
+            slots: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub(crate) fn push(&mut self, fut: F) {
+        self.slots.push_back(Slot::Pending(Box::pin(fut)));
+    }
+
+    /// Wait for whichever future finishes first; `None` if empty.
+    pub(crate) async fn next_unordered(&mut self) -> Option<F::Output> {
+        poll_fn(|cx| self.poll_next_unordered(cx)).await
+    }
+
+    /// Poll for the oldest future, polling the others along the way; `Ready(None)` if empty.
+    pub(crate) fn poll_next_ordered(&mut self, cx: &mut Context<'_>) -> Poll<Option<F::Output>> {
+        self.poll_pending(cx);
+        match self.slots.front() {
+            None => Poll::Ready(None),
+            Some(Slot::Pending(_)) => Poll::Pending,
+            Some(Slot::Done(_)) => Poll::Ready(self.slots.pop_front().map(Slot::into_output)),
+        }
+    }
+
+    /// Poll for whichever future finishes first; `Ready(None)` if empty.
+    pub(crate) fn poll_next_unordered(&mut self, cx: &mut Context<'_>) -> Poll<Option<F::Output>> {
+        if self.slots.is_empty() {
+            return Poll::Ready(None);
+        }
+        self.poll_pending(cx);
+        match self.slots.iter().position(Slot::is_done) {
+            None => Poll::Pending,
+            Some(i) => Poll::Ready(self.slots.remove(i).map(Slot::into_output)),
+        }
+    }
+
+    fn poll_pending(&mut self, cx: &mut Context<'_>) {
+        for slot in self.slots.iter_mut() {
+            if let Slot::Pending(fut) = slot {
+                if let Poll::Ready(x) = fut.as_mut().poll(cx) {
+                    *slot = Slot::Done(x);
+                }
+            }
+        }
+    }
+}
+
+impl<F> Slot<F>
+where
+    F: Future,
+{
+    fn is_done(&self) -> bool {
+        matches!(self, Slot::Done(_))
+    }
+
+    fn into_output(self) -> F::Output {
+        match self {
+            Slot::Done(x) => x,
+            Slot::Pending(_) => unreachable!("only finished slots are removed"),
+        }
+    }
+}
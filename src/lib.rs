@@ -1,15 +1,45 @@
 #![doc = include_str!("../README.md")]
-mod asyn;
+pub mod asyn;
+pub(crate) mod futpool;
 pub(crate) mod optutil;
-mod syn;
+pub mod syn;
 
 pub mod adapters;
+pub mod blocking;
+#[cfg(feature = "tokio")]
+pub mod tokio;
 
 pub use self::asyn::{
-    AsyncEndlessMoveIterator, AsyncFiniteMoveIterator, AsyncTerminalMoveIterator,
+    AsyncEndlessMoveIterator, AsyncFiniteMoveIterator, AsyncFiniteMoveIteratorExt,
+    AsyncTerminalMoveIterator, AsyncTerminalMoveIteratorExt, LocalAsyncEndlessMoveIterator,
+    LocalAsyncFiniteMoveIterator, LocalAsyncFiniteMoveIteratorExt, LocalAsyncTerminalMoveIterator,
+    LocalAsyncTerminalMoveIteratorExt,
+};
+
+#[cfg(feature = "futures")]
+pub use self::asyn::{
+    async_finite_move_iterator_from_stream, async_terminal_move_iterator_from_try_stream,
 };
 
 pub use self::syn::{
-    terminal_move_iterator_from_result_iterator, terminal_move_iterator_into_result_iterator,
+    terminal_move_iterator_from_result_iterator,
+    terminal_move_iterator_from_result_iterator_accumulating,
+    terminal_move_iterator_from_result_iterator_accumulating_into,
+    terminal_move_iterator_into_result_iterator,
+    terminal_move_iterator_into_result_iterator_accumulated, EndlessMoveIterator,
+    FiniteMoveIterator, FiniteMoveIteratorExt, FromTerminalMoveIterator, TerminalMoveIterator,
+    TerminalMoveIteratorExt,
+};
+
+#[cfg(feature = "derive")]
+pub use moveiter_derive::{
+    AsyncEndlessMoveIterator, AsyncFiniteMoveIterator, AsyncTerminalMoveIterator,
     EndlessMoveIterator, FiniteMoveIterator, TerminalMoveIterator,
 };
+
+// Paths used by the code which `moveiter_derive` generates.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use either::Either;
+}
@@ -1,12 +1,16 @@
 //! The synchronous family of move iterator traits.
 
-mod endless;
-mod finite;
-mod terminal;
+pub mod endless;
+pub mod finite;
+pub mod terminal;
 
 pub use self::endless::EndlessMoveIterator;
-pub use self::finite::FiniteMoveIterator;
+pub use self::finite::{FiniteMoveIterator, FiniteMoveIteratorExt};
 pub use self::terminal::{
-    terminal_move_iterator_from_result_iterator, terminal_move_iterator_into_result_iterator,
-    TerminalMoveIterator,
+    terminal_move_iterator_from_result_iterator,
+    terminal_move_iterator_from_result_iterator_accumulating,
+    terminal_move_iterator_from_result_iterator_accumulating_into,
+    terminal_move_iterator_into_result_iterator,
+    terminal_move_iterator_into_result_iterator_accumulated, FromTerminalMoveIterator,
+    TerminalMoveIterator, TerminalMoveIteratorExt,
 };
@@ -1,6 +1,13 @@
-//! The [EndlessMoveIterator] trait.
+//! The [EndlessMoveIterator] trait and its constructors.
 
-use crate::adapters::{EmiAsAsync, EmiAsFinite, EmiAsIterator, EmiAsTerminal};
+mod constructors;
+
+use crate::adapters::{
+    EmiAsAsync, EmiAsFinite, EmiAsIterator, EmiAsTerminal, EmiFilter, EmiFilterMap, EmiInspect,
+    EmiMap, EmiMapWhile, EmiTake,
+};
+
+pub use self::constructors::{repeat_with, unfold};
 
 /// Produce an endless sequence of `Item` values synchronously, using move semantics.
 pub trait EndlessMoveIterator: Sized {
@@ -28,4 +35,89 @@ pub trait EndlessMoveIterator: Sized {
     fn into_terminal_move_iterator(self) -> EmiAsTerminal<Self> {
         EmiAsTerminal(self)
     }
+
+    /// Transform each `Item` with `f`.
+    fn map<B, F>(self, f: F) -> EmiMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        EmiMap(self, f)
+    }
+
+    /// Produce only the `Item`s for which `pred` returns `true`.
+    ///
+    /// The result is still endless, so [EndlessMoveIterator::into_next] never returns if `pred`
+    /// rejects every remaining `Item`.
+    fn filter<P>(self, pred: P) -> EmiFilter<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        EmiFilter(self, pred)
+    }
+
+    /// Produce the `Some` results of `f` on each `Item`.
+    ///
+    /// The result is still endless, so [EndlessMoveIterator::into_next] never returns if `f`
+    /// returns `None` for every remaining `Item`.
+    fn filter_map<B, F>(self, f: F) -> EmiFilterMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        EmiFilterMap(self, f)
+    }
+
+    /// Call `f` on a reference to each `Item` before producing it.
+    fn inspect<F>(self, f: F) -> EmiInspect<Self, F>
+    where
+        F: FnMut(&Self::Item),
+    {
+        EmiInspect(self, f)
+    }
+
+    /// Transform each `Item` with `f` until it returns `None`.
+    ///
+    /// Since this may end, the result is a [TerminalMoveIterator](crate::TerminalMoveIterator)
+    /// whose `Terminal` is the remaining endless iterator (the rejected `Item` is consumed by `f`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::{EndlessMoveIterator, TerminalMoveIterator};
+    ///
+    /// struct Naturals(u64);
+    ///
+    /// impl EndlessMoveIterator for Naturals {
+    ///     type Item = u64;
+    ///
+    ///     fn into_next(self) -> (Self, u64) {
+    ///         (Naturals(self.0 + 1), self.0)
+    ///     }
+    /// }
+    ///
+    /// let mut evens = vec![];
+    /// let rest = Naturals(0)
+    ///     .filter(|n| n % 2 == 0)
+    ///     .map_while(|n| if n < 6 { Some(n) } else { None })
+    ///     .for_each(|n| evens.push(n));
+    ///
+    /// assert_eq!(evens, vec![0, 2, 4]);
+    ///
+    /// // The remainder is still an endless iterator of evens:
+    /// let (_, next) = rest.into_next();
+    /// assert_eq!(next, 8);
+    /// ```
+    fn map_while<B, F>(self, f: F) -> EmiMapWhile<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        EmiMapWhile(self, f)
+    }
+
+    /// Produce exactly `n` `Item`s.
+    ///
+    /// The result is a [TerminalMoveIterator](crate::TerminalMoveIterator) whose `Terminal` is the
+    /// remaining endless iterator.
+    fn take(self, n: usize) -> EmiTake<Self> {
+        EmiTake(self, n)
+    }
 }
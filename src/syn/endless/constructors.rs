@@ -0,0 +1,29 @@
+use crate::adapters::{EmiRepeatWith, EmiUnfold};
+
+/// Create an [EndlessMoveIterator](crate::EndlessMoveIterator) by threading `state` through `f`,
+/// which produces the next `(state, Item)` pair.
+///
+/// # Example
+///
+/// ```
+/// use moveiter::EndlessMoveIterator;
+///
+/// let fib = moveiter::syn::endless::unfold((0, 1), |(a, b)| ((b, a + b), a));
+///
+/// let items: Vec<u32> = fib.into_iter().take(6).collect();
+/// assert_eq!(items, vec![0, 1, 1, 2, 3, 5]);
+/// ```
+pub fn unfold<S, F, B>(state: S, f: F) -> EmiUnfold<S, F>
+where
+    F: FnMut(S) -> (S, B),
+{
+    EmiUnfold(state, f)
+}
+
+/// Create an [EndlessMoveIterator](crate::EndlessMoveIterator) which calls `f` for each `Item`.
+pub fn repeat_with<F, B>(f: F) -> EmiRepeatWith<F>
+where
+    F: FnMut() -> B,
+{
+    EmiRepeatWith(f)
+}
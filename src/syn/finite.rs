@@ -1,6 +1,15 @@
-//! The [FiniteMoveIterator] trait.
+//! The [FiniteMoveIterator] and [FiniteMoveIteratorExt] traits and their constructors.
 
-use crate::adapters::{FmiAsAsync, FmiAsIterator, FmiAsTerminal};
+mod constructors;
+
+use crate::adapters::{
+    FmiAsAsync, FmiAsEndlessOr, FmiAsIterator, FmiAsTerminal, FmiChain, FmiFilter, FmiFilterMap,
+    FmiInspect, FmiMap, FmiMapWhile, FmiPeekable, FmiScan, FmiZip, FmiZipLongest,
+};
+use either::Either;
+use std::ops::ControlFlow;
+
+pub use self::constructors::{empty, from_fn, successors, unfold};
 
 /// Produce a sequence of 0 or more `Item` values asynchronously, using move semantics.
 ///
@@ -26,6 +35,174 @@ pub trait FiniteMoveIterator: Sized {
     }
 }
 
+/// The adapters of a [FiniteMoveIterator], implemented for every [FiniteMoveIterator].
+///
+/// These are kept out of [FiniteMoveIterator] because most share a name with an [Iterator]
+/// method, and every [Iterator] is a [FiniteMoveIterator]. Importing only the base trait leaves
+/// calls on an [Iterator] unambiguous, and with this trait in scope, the fully qualified form
+/// `FiniteMoveIteratorExt::map(it, f)` selects these on an [Iterator] value.
+pub trait FiniteMoveIteratorExt: FiniteMoveIterator {
+    /// Convert into an [EndlessMoveIterator](crate::EndlessMoveIterator) which produces each
+    /// `Item`, then produces `fallback()` forever after.
+    fn into_endless_or<F>(self, fallback: F) -> FmiAsEndlessOr<Self, F>
+    where
+        F: FnMut() -> Self::Item,
+    {
+        FmiAsEndlessOr(Some(self), fallback)
+    }
+
+    /// Transform each `Item` with `f`.
+    fn map<B, F>(self, f: F) -> FmiMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        FmiMap(self, f)
+    }
+
+    /// Produce only the `Item`s for which `pred` returns `true`.
+    fn filter<P>(self, pred: P) -> FmiFilter<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        FmiFilter(self, pred)
+    }
+
+    /// Produce the `Some` results of `f` on each `Item`.
+    fn filter_map<B, F>(self, f: F) -> FmiFilterMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        FmiFilterMap(self, f)
+    }
+
+    /// Call `f` on a reference to each `Item` before producing it.
+    fn inspect<F>(self, f: F) -> FmiInspect<Self, F>
+    where
+        F: FnMut(&Self::Item),
+    {
+        FmiInspect(self, f)
+    }
+
+    /// Transform each `Item` with `f` until it returns `None`, which terminates iteration.
+    fn map_while<B, F>(self, f: F) -> FmiMapWhile<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        FmiMapWhile(self, f)
+    }
+
+    /// Produce each `Item` of `self`, then each `Item` of `other`.
+    fn chain<J>(self, other: J) -> FmiChain<Self, J>
+    where
+        J: FiniteMoveIterator<Item = Self::Item>,
+    {
+        FmiChain(Either::Left((self, other)))
+    }
+
+    /// Produce pairs of `Item`s from `self` and `other` until either ends.
+    ///
+    /// The result is a [TerminalMoveIterator](crate::TerminalMoveIterator) whose
+    /// [ZipTerminal](crate::adapters::ZipTerminal) identifies which side ended first, and carries
+    /// the remainder of the other side.
+    fn zip<J>(self, other: J) -> FmiZip<Self, J>
+    where
+        J: FiniteMoveIterator,
+    {
+        FmiZip(self, other)
+    }
+
+    /// Produce [EitherOrBoth](crate::adapters::EitherOrBoth) `Item`s from `self` and `other`
+    /// until both end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::adapters::EitherOrBoth::{Both, Left};
+    /// use moveiter::{FiniteMoveIterator, FiniteMoveIteratorExt};
+    ///
+    /// let items: Vec<_> = (0..3).zip_longest("ab".chars()).into_iter().collect();
+    ///
+    /// assert_eq!(items, vec![Both(0, 'a'), Both(1, 'b'), Left(2)]);
+    /// ```
+    fn zip_longest<J>(self, other: J) -> FmiZipLongest<Self, J>
+    where
+        J: FiniteMoveIterator,
+    {
+        FmiZipLongest::new(self, other)
+    }
+
+    /// Adapt `self` to support lookahead with [FmiPeekable::peek], and to put `Item`s back in
+    /// front with [FmiPeekable::push_back].
+    fn peekable(self) -> FmiPeekable<Self> {
+        FmiPeekable::new(self)
+    }
+
+    /// Thread a mutable `state` through `f` on each `Item`, producing the `Some` results of `f`.
+    ///
+    /// Returning `None` skips an `Item` rather than ending iteration. The result is a
+    /// [TerminalMoveIterator](crate::TerminalMoveIterator) whose `Terminal` is the final state.
+    fn scan<S, B, F>(self, init: S, f: F) -> FmiScan<Self, S, F>
+    where
+        F: FnMut(&mut S, Self::Item) -> Option<B>,
+    {
+        FmiScan(self, init, f)
+    }
+
+    /// Call a closure on each `Item` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value.
+    fn try_for_each<B, F>(self, mut f: F) -> ControlFlow<(Self, B)>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<B>,
+    {
+        use ControlFlow::{Break, Continue};
+
+        let mut it = self;
+
+        while let Some((next, x)) = it.into_next() {
+            match f(x) {
+                Continue(()) => {
+                    it = next;
+                }
+                Break(b) => {
+                    return Break((next, b));
+                }
+            }
+        }
+
+        Continue(())
+    }
+
+    /// Folds `Item`s into an accumulator with `f` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value, otherwise
+    /// the final accumulator is returned.
+    fn try_fold<A, B, F>(self, mut acc: A, mut f: F) -> ControlFlow<(Self, B), A>
+    where
+        F: FnMut(A, Self::Item) -> ControlFlow<B, A>,
+    {
+        use ControlFlow::{Break, Continue};
+
+        let mut it = self;
+
+        while let Some((next, x)) = it.into_next() {
+            match f(acc, x) {
+                Continue(a) => {
+                    it = next;
+                    acc = a;
+                }
+                Break(b) => {
+                    return Break((next, b));
+                }
+            }
+        }
+
+        Continue(acc)
+    }
+}
+
+impl<I> FiniteMoveIteratorExt for I where I: FiniteMoveIterator {}
+
 impl<I> FiniteMoveIterator for I
 where
     I: Iterator + Sized,
@@ -0,0 +1,49 @@
+use crate::adapters::{FmiEmpty, FmiFromFn, FmiSuccessors, FmiUnfold};
+use std::marker::PhantomData;
+
+/// Create a [FiniteMoveIterator](crate::FiniteMoveIterator) by threading `state` through `f`,
+/// which produces the next `(state, Item)` pair, or `None` to end.
+pub fn unfold<S, F, B>(state: S, f: F) -> FmiUnfold<S, F>
+where
+    F: FnMut(S) -> Option<(S, B)>,
+{
+    FmiUnfold(state, f)
+}
+
+/// Create a [FiniteMoveIterator](crate::FiniteMoveIterator) which calls `f` for each `Item` until
+/// it returns `None`.
+pub fn from_fn<F, B>(f: F) -> FmiFromFn<F>
+where
+    F: FnMut() -> Option<B>,
+{
+    FmiFromFn(f)
+}
+
+/// Create a [FiniteMoveIterator](crate::FiniteMoveIterator) which produces `first`, then each
+/// successor computed by `succ` from the previous `Item`, until `succ` returns `None`.
+///
+/// # Example
+///
+/// ```
+/// use moveiter::FiniteMoveIterator;
+///
+/// let it = moveiter::syn::finite::successors(Some(1u8), |&n| n.checked_mul(10));
+///
+/// let mut items = vec![];
+/// for x in it.into_iter() {
+///     items.push(x);
+/// }
+///
+/// assert_eq!(items, vec![1, 10, 100]);
+/// ```
+pub fn successors<T, F>(first: Option<T>, succ: F) -> FmiSuccessors<T, F>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    FmiSuccessors(first, succ)
+}
+
+/// Create a [FiniteMoveIterator](crate::FiniteMoveIterator) which produces no items.
+pub fn empty<T>() -> FmiEmpty<T> {
+    FmiEmpty(PhantomData)
+}
@@ -1,13 +1,31 @@
-//! The [TerminalMoveIterator] trait.
+//! The [TerminalMoveIterator] and [TerminalMoveIteratorExt] traits and their constructors.
 
+mod constructors;
 mod from_res_iter;
+mod from_tmi;
 mod into_res_iter;
 
-use crate::adapters::TmiAsAsync;
+use crate::adapters::{
+    TerminalSlot, TmiAndThenTerminal, TmiAsAsync, TmiAsEndlessOr, TmiAsFiniteDiscarding,
+    TmiAsFiniteWithSlot, TmiAsIterator, TmiContinueWith, TmiFilter, TmiFilterMap, TmiInspect,
+    TmiMap, TmiMapErr, TmiMapTerminal, TmiMapWhile, TmiPeekable, TmiScan, TmiSkipWhile, TmiTake,
+    TmiTakeWhile, TmiWithDeadline, TmiZip, TmiZipLongest,
+};
 use either::Either;
+use std::ops::ControlFlow;
+use std::time::Instant;
 
-pub use self::from_res_iter::terminal_move_iterator_from_result_iterator;
-pub use self::into_res_iter::terminal_move_iterator_into_result_iterator;
+pub use self::constructors::{from_fn, once_then, terminal_only, unfold};
+pub use self::from_res_iter::{
+    terminal_move_iterator_from_result_iterator,
+    terminal_move_iterator_from_result_iterator_accumulating,
+    terminal_move_iterator_from_result_iterator_accumulating_into,
+};
+pub use self::from_tmi::FromTerminalMoveIterator;
+pub use self::into_res_iter::{
+    terminal_move_iterator_into_result_iterator,
+    terminal_move_iterator_into_result_iterator_accumulated,
+};
 
 /// Produce a sequence of 0 or more `Item` values asynchronously, then produce a `Terminal` value, using move semantics.
 pub trait TerminalMoveIterator: Sized {
@@ -23,6 +41,70 @@ pub trait TerminalMoveIterator: Sized {
         TmiAsAsync(self)
     }
 
+    /// Convert into a [std::iter::Iterator] value, which keeps the `Terminal` once exhausted.
+    ///
+    /// The `Terminal` is retrieved with [TmiAsIterator::into_terminal], which gives back the
+    /// iterator if it has not yet ended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::TerminalMoveIterator;
+    /// use either::Either::{self, Left, Right};
+    ///
+    /// struct Records(Vec<&'static str>);
+    ///
+    /// impl TerminalMoveIterator for Records {
+    ///     type Item = &'static str;
+    ///     type Terminal = usize;
+    ///
+    ///     fn into_next(mut self) -> Either<(Self, Self::Item), usize> {
+    ///         if self.0.len() > 1 {
+    ///             let x = self.0.remove(0);
+    ///             Left((self, x))
+    ///         } else {
+    ///             Right(self.0[0].parse().unwrap())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut it = Records(vec!["a", "b", "2"]).into_iter();
+    /// let mut items = vec![];
+    /// for x in &mut it {
+    ///     items.push(x);
+    /// }
+    ///
+    /// assert_eq!(items, vec!["a", "b"]);
+    /// assert_eq!(it.into_terminal().ok(), Some(2));
+    /// ```
+    fn into_iter(self) -> TmiAsIterator<Self> {
+        TmiAsIterator::new(self)
+    }
+
+    /// Run `f` with this as a [std::iter::Iterator], then produce its result with the `Terminal`,
+    /// or with the iterator if `f` did not exhaust it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::TerminalMoveIterator;
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("bad")]);
+    ///
+    /// let (sum, term) = it.with_iter(|it| it.sum::<i32>());
+    ///
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(term.ok(), Some(Err("bad")));
+    /// ```
+    fn with_iter<R, F>(self, f: F) -> (R, Result<Self::Terminal, TmiAsIterator<Self>>)
+    where
+        F: FnOnce(&mut TmiAsIterator<Self>) -> R,
+    {
+        let mut it = self.into_iter();
+        let r = f(&mut it);
+        (r, it.into_terminal())
+    }
+
     /// Call a closure on each `Item`, then return the `Terminal`.
     fn for_each<F>(self, mut f: F) -> Self::Terminal
     where
@@ -70,6 +152,638 @@ pub trait TerminalMoveIterator: Sized {
     }
 }
 
+/// The adapters and consumers of a [TerminalMoveIterator], implemented for every
+/// [TerminalMoveIterator].
+///
+/// Many of these share a name with an [Iterator] method, so they live apart from the base trait
+/// which every [Iterator] implements. An [Iterator] value can use them after
+/// [into_terminal_move_iterator](crate::FiniteMoveIterator::into_terminal_move_iterator), or
+/// through the fully qualified form, such as `TerminalMoveIteratorExt::map(it, f)`.
+pub trait TerminalMoveIteratorExt: TerminalMoveIterator {
+    /// Convert into a [FiniteMoveIterator](crate::FiniteMoveIterator), dropping the `Terminal`.
+    fn into_finite_discarding_terminal(self) -> TmiAsFiniteDiscarding<Self> {
+        TmiAsFiniteDiscarding(self)
+    }
+
+    /// Convert into a [FiniteMoveIterator](crate::FiniteMoveIterator) paired with a
+    /// [TerminalSlot], which receives the `Terminal` once iteration ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::{FiniteMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("bad")]);
+    ///
+    /// let (it, slot) = it.into_finite_with_terminal_slot();
+    /// assert_eq!(slot.take(), None);
+    ///
+    /// let mut items = vec![];
+    /// for x in it.into_iter() {
+    ///     items.push(x);
+    /// }
+    /// assert_eq!(items, vec![1, 2]);
+    /// assert_eq!(slot.take(), Some(Err("bad")));
+    /// ```
+    fn into_finite_with_terminal_slot(
+        self,
+    ) -> (TmiAsFiniteWithSlot<Self>, TerminalSlot<Self::Terminal>) {
+        let slot = TerminalSlot::new();
+        (TmiAsFiniteWithSlot(self, slot.clone()), slot)
+    }
+
+    /// Convert into an [EndlessMoveIterator](crate::EndlessMoveIterator) which produces each
+    /// `Item`, then produces `fallback(&terminal)` forever after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::{EndlessMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Err(-1)]);
+    ///
+    /// let it = it.into_endless_or(|term: &Result<(), i32>| term.map_or_else(|e| e * 10, |()| 0));
+    /// let (it, a) = it.into_next();
+    /// let (it, b) = it.into_next();
+    /// let (_, c) = it.into_next();
+    ///
+    /// assert_eq!((a, b, c), (1, -10, -10));
+    /// ```
+    fn into_endless_or<F>(self, fallback: F) -> TmiAsEndlessOr<Self, F>
+    where
+        F: FnMut(&Self::Terminal) -> Self::Item,
+    {
+        TmiAsEndlessOr(Either::Left(self), fallback)
+    }
+
+    /// Transform each `Item` with `f`, passing `Terminal` through.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok(1), Ok(2), Err("bad")]);
+    ///
+    /// let mut items = vec![];
+    /// let term = it.map(|x| x * 10).for_each(|x| items.push(x));
+    ///
+    /// assert_eq!(items, vec![10, 20]);
+    /// assert_eq!(term, Err("bad"));
+    /// ```
+    fn map<B, F>(self, f: F) -> TmiMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        TmiMap(self, f)
+    }
+
+    /// Produce only the `Item`s for which `pred` returns `true`, passing `Terminal` through.
+    fn filter<P>(self, pred: P) -> TmiFilter<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TmiFilter(self, pred)
+    }
+
+    /// Produce the `Some` results of `f` on each `Item`, passing `Terminal` through.
+    fn filter_map<B, F>(self, f: F) -> TmiFilterMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        TmiFilterMap(self, f)
+    }
+
+    /// Call `f` on a reference to each `Item` before producing it, passing `Terminal` through.
+    fn inspect<F>(self, f: F) -> TmiInspect<Self, F>
+    where
+        F: FnMut(&Self::Item),
+    {
+        TmiInspect(self, f)
+    }
+
+    /// Transform each `Item` with `f` until it returns `None`.
+    ///
+    /// The resulting `Terminal` is `Left` with the remaining iterator if `f` returned `None` (the
+    /// rejected `Item` is consumed by `f`), or `Right` with `Self::Terminal` if `self` terminated
+    /// first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(
+    ///     vec![Ok(1), Ok(2), Ok(-3), Ok(4)],
+    /// );
+    ///
+    /// let mut items = vec![];
+    /// let rest = it
+    ///     .map_while(|x| if x > 0 { Some(x) } else { None })
+    ///     .for_each(|x| items.push(x))
+    ///     .left()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(items, vec![1, 2]);
+    /// assert_eq!(rest.into_next().left().unwrap().1, 4);
+    /// ```
+    fn map_while<B, F>(self, f: F) -> TmiMapWhile<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        TmiMapWhile(self, f)
+    }
+
+    /// Produce at most `n` `Item`s.
+    ///
+    /// The resulting `Terminal` is `Left` with the untouched remainder of `self` if `n` `Item`s
+    /// were produced, or `Right` with `Self::Terminal` if `self` terminated first.
+    ///
+    /// # Example
+    ///
+    /// Read a fixed-size prefix, then keep using the rest:
+    ///
+    /// ```
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(
+    ///     vec![Ok(2), Ok(7), Ok(8), Ok(9)],
+    /// );
+    ///
+    /// let mut header = vec![];
+    /// let rest = it.take(1).for_each(|x| header.push(x)).left().unwrap();
+    ///
+    /// let mut body = vec![];
+    /// let rest = rest.take(header[0]).for_each(|x| body.push(x)).left().unwrap();
+    ///
+    /// assert_eq!(header, vec![2]);
+    /// assert_eq!(body, vec![7, 8]);
+    /// assert_eq!(rest.into_next().left().unwrap().1, 9);
+    /// ```
+    fn take(self, n: usize) -> TmiTake<Self> {
+        TmiTake(self, n)
+    }
+
+    /// Produce `Item`s until `deadline` passes.
+    ///
+    /// The deadline is checked before each step, so an `into_next` already in progress is never
+    /// interrupted. The resulting `Terminal` is `Left` with the untouched remainder of `self` if
+    /// the deadline passed, or `Right` with `Self::Terminal` if `self` terminated first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(vec![Ok(1), Ok(2)]);
+    ///
+    /// let past = Instant::now() - Duration::from_millis(1);
+    /// let it = it.with_deadline(past).for_each(|_| unreachable!()).left().unwrap();
+    ///
+    /// let far = Instant::now() + Duration::from_secs(60);
+    /// let mut v = vec![];
+    /// let term = it.with_deadline(far).for_each(|x| v.push(x));
+    ///
+    /// assert_eq!(v, vec![1, 2]);
+    /// assert!(matches!(term, either::Either::Right(Ok(()))));
+    /// ```
+    fn with_deadline(self, deadline: Instant) -> TmiWithDeadline<Self> {
+        TmiWithDeadline(self, deadline)
+    }
+
+    /// Produce `Item`s while `pred` returns `true`.
+    ///
+    /// The resulting `Terminal` is `Left` with the remainder of `self` and the first rejected
+    /// `Item`, or `Right` with `Self::Terminal` if `self` terminated first.
+    fn take_while<P>(self, pred: P) -> TmiTakeWhile<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TmiTakeWhile(self, pred)
+    }
+
+    /// Skip `Item`s while `pred` returns `true`, then produce all remaining `Item`s, passing
+    /// `Terminal` through.
+    fn skip_while<P>(self, pred: P) -> TmiSkipWhile<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TmiSkipWhile(self, Some(pred))
+    }
+
+    /// Thread a mutable `state` through `f` on each `Item`, producing the `Some` results of `f`.
+    ///
+    /// Unlike [std::iter::Iterator::scan], returning `None` skips an `Item` rather than ending
+    /// iteration, which suits stateful decoders, and the final state is kept in the resulting
+    /// `(state, Terminal)` pair rather than being dropped.
+    ///
+    /// # Example
+    ///
+    /// Decode lines from chunks, keeping any trailing partial line:
+    ///
+    /// ```
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// let chunks = moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(vec![
+    ///     Ok("ab"),
+    ///     Ok("c\nd"),
+    ///     Ok("e"),
+    /// ]);
+    ///
+    /// let mut lines = vec![];
+    /// let (partial, term) = chunks
+    ///     .scan(String::new(), |buf, chunk| {
+    ///         buf.push_str(chunk);
+    ///         buf.find('\n').map(|i| {
+    ///             let line = buf[..i].to_string();
+    ///             buf.replace_range(..=i, "");
+    ///             line
+    ///         })
+    ///     })
+    ///     .for_each(|line| lines.push(line));
+    ///
+    /// assert_eq!(lines, vec!["abc"]);
+    /// assert_eq!(partial, "de");
+    /// assert_eq!(term, Ok(()));
+    /// ```
+    fn scan<S, B, F>(self, init: S, f: F) -> TmiScan<Self, S, F>
+    where
+        F: FnMut(&mut S, Self::Item) -> Option<B>,
+    {
+        TmiScan(self, init, f)
+    }
+
+    /// Transform the `Terminal` with `f`, passing each `Item` through.
+    fn map_terminal<R, F>(self, f: F) -> TmiMapTerminal<Self, F>
+    where
+        F: FnOnce(Self::Terminal) -> R,
+    {
+        TmiMapTerminal(self, f)
+    }
+
+    /// Transform the error of a [Result] `Terminal` with `f`, passing each `Item` through.
+    fn map_err<T, E, E2, F>(self, f: F) -> TmiMapErr<Self, F>
+    where
+        Self: TerminalMoveIterator<Terminal = Result<T, E>>,
+        F: FnOnce(E) -> E2,
+    {
+        TmiMapErr(self, f)
+    }
+
+    /// Convert the error of a [Result] `Terminal` with [From], as the `?` operator does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ParseError(std::num::ParseIntError);
+    ///
+    /// impl From<std::num::ParseIntError> for ParseError {
+    ///     fn from(e: std::num::ParseIntError) -> Self {
+    ///         ParseError(e)
+    ///     }
+    /// }
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![
+    ///     "1".parse::<i32>(),
+    ///     "x".parse::<i32>(),
+    /// ]);
+    ///
+    /// let term: Result<(), ParseError> = it.err_into().for_each(|_| {});
+    /// assert!(term.is_err());
+    /// ```
+    fn err_into<T, E, E2>(self) -> TmiMapErr<Self, fn(E) -> E2>
+    where
+        Self: TerminalMoveIterator<Terminal = Result<T, E>>,
+        E2: From<E>,
+    {
+        TmiMapErr(self, E2::from)
+    }
+
+    /// Call `f` on each `Terminal`, which either continues iteration with a fresh `Self` value
+    /// (`Left`), or produces the final `Terminal` (`Right`).
+    ///
+    /// # Example
+    ///
+    /// A paginated source can continue from each page's terminal:
+    ///
+    /// ```
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    /// use either::Either::{self, Left, Right};
+    ///
+    /// struct Page {
+    ///     items: Vec<u32>,
+    ///     next_page: Option<usize>,
+    /// }
+    ///
+    /// impl TerminalMoveIterator for Page {
+    ///     type Item = u32;
+    ///     type Terminal = Option<usize>;
+    ///
+    ///     fn into_next(mut self) -> Either<(Self, u32), Option<usize>> {
+    ///         if self.items.is_empty() {
+    ///             Right(self.next_page)
+    ///         } else {
+    ///             let x = self.items.remove(0);
+    ///             Left((self, x))
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let fetch = |n: usize| Page {
+    ///     items: vec![n as u32 * 10, n as u32 * 10 + 1],
+    ///     next_page: if n < 2 { Some(n + 1) } else { None },
+    /// };
+    ///
+    /// let mut items = vec![];
+    /// let pages = fetch(0)
+    ///     .and_then_terminal(|next| match next {
+    ///         Some(n) => Left(fetch(n)),
+    ///         None => Right("done"),
+    ///     })
+    ///     .for_each(|x| items.push(x));
+    ///
+    /// assert_eq!(items, vec![0, 1, 10, 11, 20, 21]);
+    /// assert_eq!(pages, "done");
+    /// ```
+    fn and_then_terminal<R, F>(self, f: F) -> TmiAndThenTerminal<Self, F>
+    where
+        F: FnMut(Self::Terminal) -> Either<Self, R>,
+    {
+        TmiAndThenTerminal(self, f)
+    }
+
+    /// Produce each `Item` of `self`, then pass `Terminal` to `f` to produce a second iterator,
+    /// then produce its `Item`s and `Terminal`.
+    ///
+    /// # Example
+    ///
+    /// A reader whose header terminal determines how to read the body:
+    ///
+    /// ```
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// let headers = moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(vec![
+    ///     Ok("content-length: 2"),
+    ///     Ok("encoding: upper"),
+    /// ])
+    /// .map_terminal(|res| res.map(|()| "upper"));
+    ///
+    /// let mut lines = vec![];
+    /// let term = headers
+    ///     .map(String::from)
+    ///     .continue_with(|encoding| {
+    ///         let upper = encoding == Ok("upper");
+    ///         let body = vec![Ok("abc"), Ok("def")];
+    ///         moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(body)
+    ///             .map(move |s| if upper { s.to_uppercase() } else { s.to_string() })
+    ///     })
+    ///     .for_each(|line| lines.push(line));
+    ///
+    /// assert_eq!(lines, vec!["content-length: 2", "encoding: upper", "ABC", "DEF"]);
+    /// assert_eq!(term, Ok(()));
+    /// ```
+    fn continue_with<J, F>(self, f: F) -> TmiContinueWith<Self, F, J>
+    where
+        F: FnOnce(Self::Terminal) -> J,
+        J: TerminalMoveIterator<Item = Self::Item>,
+    {
+        TmiContinueWith(Either::Left((self, f)))
+    }
+
+    /// Produce pairs of `Item`s from `self` and `other` until either terminates.
+    ///
+    /// The [ZipTerminal](crate::adapters::ZipTerminal) identifies which side ended first, and
+    /// carries the remainder of the other side, so no `Item`s are lost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::adapters::ZipTerminal;
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    ///
+    /// let names = moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(
+    ///     vec![Ok("a"), Ok("b")],
+    /// );
+    /// let values = moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(
+    ///     vec![Ok(1), Ok(2), Ok(3), Ok(4)],
+    /// );
+    ///
+    /// let mut pairs = vec![];
+    /// match names.zip(values).for_each(|pair| pairs.push(pair)) {
+    ///     ZipTerminal::LeftEnded { terminal, right } => {
+    ///         assert_eq!(terminal, Ok(()));
+    ///
+    ///         let mut rest = vec![];
+    ///         right.for_each(|x| rest.push(x)).unwrap();
+    ///         assert_eq!(rest, vec![3, 4]);
+    ///     }
+    ///     ZipTerminal::RightEnded { .. } => unreachable!(),
+    /// }
+    ///
+    /// assert_eq!(pairs, vec![("a", 1), ("b", 2)]);
+    /// ```
+    fn zip<J>(self, other: J) -> TmiZip<Self, J>
+    where
+        J: TerminalMoveIterator,
+    {
+        TmiZip(self, other)
+    }
+
+    /// Produce [EitherOrBoth](crate::adapters::EitherOrBoth) `Item`s from `self` and `other`
+    /// until both terminate, then produce both `Terminal`s.
+    fn zip_longest<J>(self, other: J) -> TmiZipLongest<Self, J>
+    where
+        J: TerminalMoveIterator,
+    {
+        TmiZipLongest::new(self, other)
+    }
+
+    /// Adapt `self` to support lookahead of the next `Item` or the `Terminal` with
+    /// [TmiPeekable::peek], and to put `Item`s back in front with [TmiPeekable::push_back].
+    fn peekable(self) -> TmiPeekable<Self> {
+        TmiPeekable::new(self)
+    }
+
+    /// Collect every `Item` into `C`, and return it along with the `Terminal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::TerminalMoveIteratorExt;
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator(vec![Ok('h'), Ok('i'), Err(3)]);
+    ///
+    /// let (s, term): (String, _) = it.collect();
+    /// assert_eq!(s, "hi");
+    /// assert_eq!(term, Err(3));
+    /// ```
+    fn collect<C>(self) -> (C, Self::Terminal)
+    where
+        C: FromTerminalMoveIterator<Self::Item>,
+    {
+        C::from_terminal_move_iterator(self)
+    }
+
+    /// Collect every `Item` into `C` if the [Result] `Terminal` is `Ok`, otherwise return the
+    /// error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::TerminalMoveIteratorExt;
+    ///
+    /// fn read_numbers(input: &str) -> std::io::Result<Vec<String>> {
+    ///     use std::io::BufRead;
+    ///
+    ///     moveiter::terminal_move_iterator_from_result_iterator(input.as_bytes().lines())
+    ///         .try_collect()
+    /// }
+    ///
+    /// assert_eq!(read_numbers("1\n2\n").unwrap(), vec!["1", "2"]);
+    /// ```
+    fn try_collect<C, E>(self) -> Result<C, E>
+    where
+        Self: TerminalMoveIterator<Terminal = Result<(), E>>,
+        C: FromTerminalMoveIterator<Self::Item>,
+    {
+        let (collection, term) = self.collect();
+        term.map(|()| collection)
+    }
+
+    /// Collect the pair `Item`s into two separate collections, and return them along with the
+    /// `Terminal`.
+    fn unzip<A, B, CA, CB>(self) -> (CA, CB, Self::Terminal)
+    where
+        Self: TerminalMoveIterator<Item = (A, B)>,
+        CA: FromTerminalMoveIterator<A>,
+        CB: FromTerminalMoveIterator<B>,
+    {
+        let mut left = CA::default();
+        let mut right = CB::default();
+        let term = self.for_each(|(a, b)| {
+            left.extend(Some(a));
+            right.extend(Some(b));
+        });
+        (left, right, term)
+    }
+
+    /// Collect the `Item`s for which `pred` returns `true` into the first collection, and the
+    /// rest into the second, and return them along with the `Terminal`.
+    fn partition<C, P>(self, mut pred: P) -> (C, C, Self::Terminal)
+    where
+        C: FromTerminalMoveIterator<Self::Item>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        let mut accepted = C::default();
+        let mut rejected = C::default();
+        let term = self.for_each(|x| {
+            if pred(&x) {
+                accepted.extend(Some(x));
+            } else {
+                rejected.extend(Some(x));
+            }
+        });
+        (accepted, rejected, term)
+    }
+
+    /// Call a closure on each `Item` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value, otherwise
+    /// the `Terminal` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use moveiter::{TerminalMoveIterator, TerminalMoveIteratorExt};
+    /// use std::ops::ControlFlow::{Break, Continue};
+    ///
+    /// let it = moveiter::terminal_move_iterator_from_result_iterator::<_, _, ()>(
+    ///     vec![Ok("a"), Ok(""), Ok("b")],
+    /// );
+    ///
+    /// let mut seen = vec![];
+    /// let flow = it.try_for_each(|s| {
+    ///     if s.is_empty() {
+    ///         Break("blank line")
+    ///     } else {
+    ///         seen.push(s);
+    ///         Continue(())
+    ///     }
+    /// });
+    ///
+    /// match flow {
+    ///     Break((rest, reason)) => {
+    ///         assert_eq!(reason, "blank line");
+    ///         assert_eq!(rest.into_next().left().unwrap().1, "b");
+    ///     }
+    ///     Continue(_) => unreachable!(),
+    /// }
+    /// assert_eq!(seen, vec!["a"]);
+    /// ```
+    fn try_for_each<B, F>(self, mut f: F) -> ControlFlow<(Self, B), Self::Terminal>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<B>,
+    {
+        use ControlFlow::{Break, Continue};
+        use Either::*;
+
+        let mut it = self;
+
+        loop {
+            match it.into_next() {
+                Left((next, x)) => match f(x) {
+                    Continue(()) => {
+                        it = next;
+                    }
+                    Break(b) => {
+                        return Break((next, b));
+                    }
+                },
+                Right(term) => {
+                    return Continue(term);
+                }
+            }
+        }
+    }
+
+    /// Folds `Item`s into an accumulator with `f` until it returns [ControlFlow::Break].
+    ///
+    /// If `f` breaks, the remaining iterator is returned along with the break value, otherwise
+    /// the final accumulator is returned with the `Terminal`.
+    fn try_fold<A, B, F>(self, mut acc: A, mut f: F) -> ControlFlow<(Self, B), (A, Self::Terminal)>
+    where
+        F: FnMut(A, Self::Item) -> ControlFlow<B, A>,
+    {
+        use ControlFlow::{Break, Continue};
+        use Either::*;
+
+        let mut it = self;
+
+        loop {
+            match it.into_next() {
+                Left((next, x)) => match f(acc, x) {
+                    Continue(a) => {
+                        it = next;
+                        acc = a;
+                    }
+                    Break(b) => {
+                        return Break((next, b));
+                    }
+                },
+                Right(term) => {
+                    return Continue((acc, term));
+                }
+            }
+        }
+    }
+}
+
+impl<I> TerminalMoveIteratorExt for I where I: TerminalMoveIterator {}
+
 impl<I> TerminalMoveIterator for I
 where
     I: Iterator + Sized + Send,
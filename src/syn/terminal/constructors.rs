@@ -0,0 +1,48 @@
+use crate::adapters::{TmiFromFn, TmiOnceThen, TmiTerminalOnly, TmiUnfold};
+use either::Either;
+use std::marker::PhantomData;
+
+/// Create a [TerminalMoveIterator](crate::TerminalMoveIterator) by threading `state` through `f`,
+/// which produces either the next `(state, Item)` pair, or the `Terminal`.
+///
+/// # Example
+///
+/// ```
+/// use moveiter::TerminalMoveIterator;
+/// use either::Either::{Left, Right};
+///
+/// let it = moveiter::syn::terminal::unfold(1, |n| if n < 100 { Left((n * 3, n)) } else { Right(n) });
+///
+/// let mut items = vec![];
+/// let term = it.for_each(|x| items.push(x));
+///
+/// assert_eq!(items, vec![1, 3, 9, 27, 81]);
+/// assert_eq!(term, 243);
+/// ```
+pub fn unfold<S, F, B, T>(state: S, f: F) -> TmiUnfold<S, F>
+where
+    F: FnMut(S) -> Either<(S, B), T>,
+{
+    TmiUnfold(state, f)
+}
+
+/// Create a [TerminalMoveIterator](crate::TerminalMoveIterator) which calls `f` for each step,
+/// producing an `Item` from `Left`, or ending with the `Terminal` from `Right`.
+pub fn from_fn<F, B, T>(f: F) -> TmiFromFn<F>
+where
+    F: FnMut() -> Either<B, T>,
+{
+    TmiFromFn(f)
+}
+
+/// Create a [TerminalMoveIterator](crate::TerminalMoveIterator) which produces `item`, then
+/// `terminal`.
+pub fn once_then<I, T>(item: I, terminal: T) -> TmiOnceThen<I, T> {
+    TmiOnceThen(Some(item), terminal)
+}
+
+/// Create a [TerminalMoveIterator](crate::TerminalMoveIterator) which produces no items, only
+/// `terminal`.
+pub fn terminal_only<I, T>(terminal: T) -> TmiTerminalOnly<I, T> {
+    TmiTerminalOnly(terminal, PhantomData)
+}
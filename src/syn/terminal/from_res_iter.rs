@@ -98,3 +98,98 @@ where
         }
     }
 }
+
+/// Convert a [std::iter::Iterator] with `Item = Result<T, E>` into a [TerminalMoveIterator]
+/// which skips every `Err` item, and terminates with all of the errors.
+///
+/// Each error is paired with the number of `Ok` items which preceded it, so
+/// [terminal_move_iterator_into_result_iterator_accumulated](crate::terminal_move_iterator_into_result_iterator_accumulated)
+/// can restore the original interleaving.
+///
+/// By contrast to [terminal_move_iterator_from_result_iterator], this does not stop at the first
+/// `Err`, which is useful for validation passes which should report every bad item.
+///
+/// # Example
+///
+/// ```
+/// use moveiter::TerminalMoveIterator;
+///
+/// let records = vec![Ok(1), Err("bad record 2"), Ok(3), Err("bad record 4")];
+///
+/// let mut sum = 0;
+/// let res = moveiter::terminal_move_iterator_from_result_iterator_accumulating(records)
+///     .for_each(|n| sum += n);
+///
+/// assert_eq!(sum, 4);
+/// assert_eq!(res, Err(vec![(1, "bad record 2"), (2, "bad record 4")]));
+/// ```
+pub fn terminal_move_iterator_from_result_iterator_accumulating<I, T, E>(
+    it: I,
+) -> impl TerminalMoveIterator<Item = T, Terminal = Result<(), Vec<(usize, E)>>>
+where
+    I: IntoIterator<Item = Result<T, E>> + Sized,
+{
+    terminal_move_iterator_from_result_iterator_accumulating_into(it)
+}
+
+/// Convert a [std::iter::Iterator] with `Item = Result<T, E>` into a [TerminalMoveIterator]
+/// which skips every `Err` item, and terminates with all of the errors, each paired with the
+/// number of preceding `Ok` items, collected into `C`.
+///
+/// This is the generalization of [terminal_move_iterator_from_result_iterator_accumulating] for
+/// any error collector.
+pub fn terminal_move_iterator_from_result_iterator_accumulating_into<I, T, E, C>(
+    it: I,
+) -> impl TerminalMoveIterator<Item = T, Terminal = Result<(), C>>
+where
+    I: IntoIterator<Item = Result<T, E>> + Sized,
+    C: Default + Extend<(usize, E)>,
+{
+    TmiFromResultIteratorAccumulating {
+        it: it.into_iter(),
+        oks: 0,
+        errors: None,
+    }
+}
+
+/// Produces the `Ok` items from the underlying [std::iter::Iterator], terminating with [Err] if
+/// any errors were encountered, otherwise terminating with [Ok].
+///
+/// The [TerminalMoveIterator] value returned by
+/// [terminal_move_iterator_from_result_iterator_accumulating_into].
+struct TmiFromResultIteratorAccumulating<I, C> {
+    it: I,
+    // The number of `Ok` items produced so far:
+    oks: usize,
+    // This is `None` until the first error:
+    errors: Option<C>,
+}
+
+impl<I, T, E, C> TerminalMoveIterator for TmiFromResultIteratorAccumulating<I, C>
+where
+    I: Iterator<Item = Result<T, E>> + Sized,
+    C: Default + Extend<(usize, E)>,
+{
+    type Terminal = Result<(), C>;
+    type Item = T;
+
+    fn into_next(mut self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        for res in self.it.by_ref() {
+            match res {
+                Ok(x) => {
+                    self.oks += 1;
+                    return Left((self, x));
+                }
+                Err(e) => {
+                    self.errors
+                        .get_or_insert_with(C::default)
+                        .extend(Some((self.oks, e)));
+                }
+            }
+        }
+
+        Right(self.errors.map_or(Ok(()), Err))
+    }
+}
@@ -0,0 +1,97 @@
+use crate::TerminalMoveIterator;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, Hash};
+
+/// Build a collection from the `Item`s of a [TerminalMoveIterator], keeping the `Terminal`.
+///
+/// This is the move iterator analog of [std::iter::FromIterator], and is used by
+/// [TerminalMoveIteratorExt::collect](crate::TerminalMoveIteratorExt::collect). The [Default] and
+/// [Extend] supertraits allow the same collections to be built by
+/// [AsyncTerminalMoveIteratorExt::collect](crate::AsyncTerminalMoveIteratorExt::collect).
+///
+/// The provided method extends a [Default] value one `Item` at a time, so most impls are empty:
+///
+/// ```
+/// use moveiter::FromTerminalMoveIterator;
+///
+/// #[derive(Default)]
+/// struct Total(u64);
+///
+/// impl Extend<u64> for Total {
+///     fn extend<I: IntoIterator<Item = u64>>(&mut self, items: I) {
+///         for x in items {
+///             self.0 += x;
+///         }
+///     }
+/// }
+///
+/// impl FromTerminalMoveIterator<u64> for Total {}
+/// ```
+pub trait FromTerminalMoveIterator<A>: Default + Extend<A> {
+    /// Build `Self` from every `Item` of `it`, and return it along with the `Terminal`.
+    fn from_terminal_move_iterator<I>(it: I) -> (Self, <I as TerminalMoveIterator>::Terminal)
+    where
+        I: TerminalMoveIterator<Item = A>,
+    {
+        let mut collection = Self::default();
+        let term = it.for_each(|x| collection.extend(Some(x)));
+        (collection, term)
+    }
+}
+
+impl<T> FromTerminalMoveIterator<T> for Vec<T> {
+    fn from_terminal_move_iterator<I>(it: I) -> (Self, <I as TerminalMoveIterator>::Terminal)
+    where
+        I: TerminalMoveIterator<Item = T>,
+    {
+        let mut v = vec![];
+        let term = it.for_each(|x| v.push(x));
+        (v, term)
+    }
+}
+
+impl<T> FromTerminalMoveIterator<T> for VecDeque<T> {
+    fn from_terminal_move_iterator<I>(it: I) -> (Self, <I as TerminalMoveIterator>::Terminal)
+    where
+        I: TerminalMoveIterator<Item = T>,
+    {
+        let mut v = VecDeque::new();
+        let term = it.for_each(|x| v.push_back(x));
+        (v, term)
+    }
+}
+
+impl<T> FromTerminalMoveIterator<T> for LinkedList<T> {}
+
+impl<T> FromTerminalMoveIterator<T> for BinaryHeap<T> where T: Ord {}
+
+impl<T> FromTerminalMoveIterator<T> for BTreeSet<T> where T: Ord {}
+
+impl<T, S> FromTerminalMoveIterator<T> for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+}
+
+impl<K, V> FromTerminalMoveIterator<(K, V)> for BTreeMap<K, V> where K: Ord {}
+
+impl<K, V, S> FromTerminalMoveIterator<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+}
+
+impl FromTerminalMoveIterator<char> for String {}
+
+impl FromTerminalMoveIterator<&char> for String {}
+
+impl FromTerminalMoveIterator<&str> for String {}
+
+impl FromTerminalMoveIterator<String> for String {}
+
+impl FromTerminalMoveIterator<Box<str>> for String {}
+
+impl<'a> FromTerminalMoveIterator<Cow<'a, str>> for String {}
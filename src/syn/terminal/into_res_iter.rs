@@ -1,5 +1,7 @@
 use crate::optutil::OptionUpdate;
 use crate::TerminalMoveIterator;
+use either::Either;
+use std::iter::Peekable;
 
 /// Convert a [TerminalMoveIterator] with `Terminal = Result<(), E>` into a
 /// [std::iter::Iterator].
@@ -38,3 +40,93 @@ where
         })
     }
 }
+
+/// Convert a [TerminalMoveIterator] with `Terminal = Result<(), C>`, where `C` is a collection of
+/// errors each paired with the number of `Ok` items which preceded it, into a
+/// [std::iter::Iterator] which produces every error as an `Err` item at its original position.
+///
+/// This is the inverse of
+/// [terminal_move_iterator_from_result_iterator_accumulating](crate::terminal_move_iterator_from_result_iterator_accumulating).
+///
+/// The error positions are only known once the `Terminal` is reached, so the `Ok` items are
+/// buffered until then.
+///
+/// # Example
+///
+/// ```
+/// let input = vec![Err("w"), Ok(1), Err("x"), Ok(2), Err("y"), Ok(3), Err("z")];
+///
+/// let it = moveiter::terminal_move_iterator_from_result_iterator_accumulating(input.clone());
+/// let results: Vec<_> =
+///     moveiter::terminal_move_iterator_into_result_iterator_accumulated(it).collect();
+///
+/// assert_eq!(results, input);
+/// ```
+pub fn terminal_move_iterator_into_result_iterator_accumulated<I, T, E, C>(
+    tmi: I,
+) -> impl Iterator<Item = Result<T, E>>
+where
+    I: TerminalMoveIterator<Item = T, Terminal = Result<(), C>>,
+    C: IntoIterator<Item = (usize, E)>,
+{
+    TmiAsAccumulatedResultIterator {
+        tmi: Some(tmi),
+        oks: vec![].into_iter(),
+        produced: 0,
+        errors: None,
+    }
+}
+
+// The `tmi` becomes `None` on the first call to `next`, which buffers every `Ok` item in `oks`
+// and the positioned errors in `errors`; `produced` counts the `Ok` items produced so far.
+struct TmiAsAccumulatedResultIterator<I, T, J>
+where
+    J: Iterator,
+{
+    tmi: Option<I>,
+    oks: std::vec::IntoIter<T>,
+    produced: usize,
+    errors: Option<Peekable<J>>,
+}
+
+impl<I, T, E, C, J> Iterator for TmiAsAccumulatedResultIterator<I, T, J>
+where
+    I: TerminalMoveIterator<Item = T, Terminal = Result<(), C>>,
+    C: IntoIterator<Item = (usize, E), IntoIter = J>,
+    J: Iterator<Item = (usize, E)>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use Either::*;
+
+        if let Some(mut tmi) = self.tmi.take() {
+            let mut oks = vec![];
+            let terminal = loop {
+                match tmi.into_next() {
+                    Left((next, x)) => {
+                        oks.push(x);
+                        tmi = next;
+                    }
+                    Right(terminal) => break terminal,
+                }
+            };
+            self.oks = oks.into_iter();
+            self.errors = terminal.err().map(|c| c.into_iter().peekable());
+        }
+
+        let produced = self.produced;
+        if let Some(errors) = self.errors.as_mut() {
+            if errors.peek().is_some_and(|&(pos, _)| pos <= produced) {
+                return errors.next().map(|(_, e)| Err(e));
+            }
+        }
+        match self.oks.next() {
+            Some(x) => {
+                self.produced += 1;
+                Some(Ok(x))
+            }
+            None => self.errors.as_mut()?.next().map(|(_, e)| Err(e)),
+        }
+    }
+}
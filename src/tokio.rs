@@ -0,0 +1,48 @@
+//! Async move iterator sources over `tokio` I/O, channels, and tasks.
+//!
+//! Each iterator owns its underlying reader, receiver, or task set and hands it back with
+//! `into_inner`. The I/O readers also return theirs in the `Terminal` at end of input, so a
+//! connection can be reused after a delimited section.
+//!
+//! # Example
+//!
+//! Read header lines up to a blank line, then read the rest of the connection directly:
+//!
+//! ```
+//! # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//! use moveiter::AsyncTerminalMoveIterator;
+//! use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader};
+//!
+//! let (mut client, server) = tokio::io::duplex(64);
+//! client.write_all(b"a: 1\r\nb: 2\r\n\r\nbody").await.unwrap();
+//! drop(client);
+//!
+//! let mut it = moveiter::tokio::lines(BufReader::new(server));
+//! let mut headers = vec![];
+//! loop {
+//!     let (next, line) = it.into_next().await.left().unwrap();
+//!     it = next;
+//!     if line.is_empty() {
+//!         break;
+//!     }
+//!     headers.push(line);
+//! }
+//!
+//! let mut body = String::new();
+//! it.into_inner().read_to_string(&mut body).await.unwrap();
+//!
+//! assert_eq!(headers, vec!["a: 1", "b: 2"]);
+//! assert_eq!(body, "body");
+//! # });
+//! ```
+
+mod io;
+mod sync;
+mod task;
+
+pub use self::io::{lines, split, Lines, Split};
+pub use self::sync::{
+    broadcast_receiver, mpsc_receiver, unbounded_receiver, BroadcastReceiver, BroadcastTerminal,
+    MpscReceiver, UnboundedReceiver,
+};
+pub use self::task::{join_set, JoinSetTasks};
@@ -0,0 +1,132 @@
+use crate::AsyncTerminalMoveIterator;
+use ::tokio::io::{AsyncBufRead, AsyncBufReadExt};
+use either::Either;
+use std::io;
+
+/// Produce each line of `reader` without its trailing `"\n"` or `"\r\n"`, then terminate with
+/// `reader` at end of input.
+///
+/// # Example
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::AsyncTerminalMoveIteratorExt;
+/// use tokio::io::{AsyncWriteExt, BufReader};
+///
+/// let (mut client, server) = tokio::io::duplex(64);
+/// client.write_all(b"one\ntwo\r\nthree").await.unwrap();
+/// drop(client);
+///
+/// let (v, term): (Vec<String>, _) = moveiter::tokio::lines(BufReader::new(server)).collect().await;
+///
+/// assert_eq!(v, vec!["one", "two", "three"]);
+/// assert!(term.is_ok());
+/// # });
+/// ```
+pub fn lines<R>(reader: R) -> Lines<R>
+where
+    R: AsyncBufRead + Unpin + Send,
+{
+    Lines(reader)
+}
+
+/// Produce each `delimiter`-separated segment of `reader` without the delimiter, then terminate
+/// with `reader` at end of input.
+///
+/// # Example
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::AsyncTerminalMoveIteratorExt;
+/// use tokio::io::{AsyncWriteExt, BufReader};
+///
+/// let (mut client, server) = tokio::io::duplex(64);
+/// client.write_all(b"a\0bc\0").await.unwrap();
+/// drop(client);
+///
+/// let (v, term): (Vec<Vec<u8>>, _) =
+///     moveiter::tokio::split(BufReader::new(server), 0).collect().await;
+///
+/// assert_eq!(v, vec![b"a".to_vec(), b"bc".to_vec()]);
+/// assert!(term.is_ok());
+/// # });
+/// ```
+pub fn split<R>(reader: R, delimiter: u8) -> Split<R>
+where
+    R: AsyncBufRead + Unpin + Send,
+{
+    Split(reader, delimiter)
+}
+
+/// An [AsyncTerminalMoveIterator] impl from [lines].
+pub struct Lines<R>(R);
+
+/// An [AsyncTerminalMoveIterator] impl from [split].
+pub struct Split<R>(R, u8);
+
+impl<R> Lines<R> {
+    /// Stop iterating and recover the reader, with any unread input still buffered.
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R> Split<R> {
+    /// Stop iterating and recover the reader, with any unread input still buffered.
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R> AsyncTerminalMoveIterator for Lines<R>
+where
+    R: AsyncBufRead + Unpin + Send,
+{
+    type Terminal = io::Result<R>;
+    type Item = String;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let Lines(mut reader) = self;
+        let mut line = String::new();
+        match reader.read_line(&mut line).await {
+            Ok(0) => Right(Ok(reader)),
+            Ok(_) => {
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+                Left((Lines(reader), line))
+            }
+            Err(e) => Right(Err(e)),
+        }
+    }
+}
+
+impl<R> AsyncTerminalMoveIterator for Split<R>
+where
+    R: AsyncBufRead + Unpin + Send,
+{
+    type Terminal = io::Result<R>;
+    type Item = Vec<u8>;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use Either::*;
+
+        let Split(mut reader, delimiter) = self;
+        let mut segment = vec![];
+        match reader.read_until(delimiter, &mut segment).await {
+            Ok(0) => Right(Ok(reader)),
+            Ok(_) => {
+                if segment.last() == Some(&delimiter) {
+                    segment.pop();
+                }
+                Left((Split(reader, delimiter), segment))
+            }
+            Err(e) => Right(Err(e)),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{AsyncFiniteMoveIterator, AsyncTerminalMoveIterator};
+use ::tokio::sync::{broadcast, mpsc};
+use either::Either;
+
+/// Produce each message from `receiver`, ending once every sender is dropped and the channel is
+/// drained.
+///
+/// # Example
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::AsyncFiniteMoveIteratorExt;
+///
+/// let (tx, rx) = tokio::sync::mpsc::channel(4);
+/// tx.send(1).await.unwrap();
+/// tx.send(2).await.unwrap();
+/// drop(tx);
+///
+/// let v: Vec<i32> = moveiter::tokio::mpsc_receiver(rx).collect().await;
+/// assert_eq!(v, vec![1, 2]);
+/// # });
+/// ```
+pub fn mpsc_receiver<T>(receiver: mpsc::Receiver<T>) -> MpscReceiver<T>
+where
+    T: Send,
+{
+    MpscReceiver(receiver)
+}
+
+/// Produce each message from `receiver`, ending once every sender is dropped and the channel is
+/// drained.
+pub fn unbounded_receiver<T>(receiver: mpsc::UnboundedReceiver<T>) -> UnboundedReceiver<T>
+where
+    T: Send,
+{
+    UnboundedReceiver(receiver)
+}
+
+/// Produce each message from `receiver`, terminating when the channel closes or the receiver
+/// lags behind.
+///
+/// # Example
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::tokio::BroadcastTerminal;
+/// use moveiter::{AsyncTerminalMoveIterator, AsyncTerminalMoveIteratorExt};
+///
+/// let (tx, rx) = tokio::sync::broadcast::channel(2);
+/// for x in 0..4 {
+///     tx.send(x).unwrap();
+/// }
+///
+/// // The two oldest messages were overwritten before they were received:
+/// let it = moveiter::tokio::broadcast_receiver(rx);
+/// let it = match it.into_next().await.right().unwrap() {
+///     BroadcastTerminal::Lagged(2, it) => it,
+///     _ => unreachable!(),
+/// };
+///
+/// drop(tx);
+/// let (v, term): (Vec<i32>, _) = it.collect().await;
+/// assert_eq!(v, vec![2, 3]);
+/// assert!(matches!(term, BroadcastTerminal::Closed));
+/// # });
+/// ```
+pub fn broadcast_receiver<T>(receiver: broadcast::Receiver<T>) -> BroadcastReceiver<T>
+where
+    T: Clone + Send,
+{
+    BroadcastReceiver(receiver)
+}
+
+/// An [AsyncFiniteMoveIterator] impl from [mpsc_receiver].
+pub struct MpscReceiver<T>(mpsc::Receiver<T>);
+
+/// An [AsyncFiniteMoveIterator] impl from [unbounded_receiver].
+pub struct UnboundedReceiver<T>(mpsc::UnboundedReceiver<T>);
+
+/// An [AsyncTerminalMoveIterator] impl from [broadcast_receiver].
+pub struct BroadcastReceiver<T>(broadcast::Receiver<T>);
+
+/// The `Terminal` of a [BroadcastReceiver].
+#[derive(Debug)]
+pub enum BroadcastTerminal<I> {
+    /// Every sender was dropped and all messages were received.
+    Closed,
+
+    /// The receiver fell behind and this many messages were skipped. Iteration can resume with
+    /// the oldest message still retained by the channel.
+    Lagged(u64, I),
+}
+
+impl<T> MpscReceiver<T> {
+    /// Stop iterating and recover the receiver.
+    pub fn into_inner(self) -> mpsc::Receiver<T> {
+        self.0
+    }
+}
+
+impl<T> UnboundedReceiver<T> {
+    /// Stop iterating and recover the receiver.
+    pub fn into_inner(self) -> mpsc::UnboundedReceiver<T> {
+        self.0
+    }
+}
+
+impl<T> BroadcastReceiver<T> {
+    /// Stop iterating and recover the receiver.
+    pub fn into_inner(self) -> broadcast::Receiver<T> {
+        self.0
+    }
+}
+
+impl<T> AsyncFiniteMoveIterator for MpscReceiver<T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let MpscReceiver(mut receiver) = self;
+        let x = receiver.recv().await?;
+        Some((MpscReceiver(receiver), x))
+    }
+}
+
+impl<T> AsyncFiniteMoveIterator for UnboundedReceiver<T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let UnboundedReceiver(mut receiver) = self;
+        let x = receiver.recv().await?;
+        Some((UnboundedReceiver(receiver), x))
+    }
+}
+
+impl<T> AsyncTerminalMoveIterator for BroadcastReceiver<T>
+where
+    T: Clone + Send,
+{
+    type Terminal = BroadcastTerminal<Self>;
+    type Item = T;
+
+    async fn into_next(self) -> Either<(Self, Self::Item), Self::Terminal> {
+        use broadcast::error::RecvError;
+        use Either::*;
+
+        let BroadcastReceiver(mut receiver) = self;
+        match receiver.recv().await {
+            Ok(x) => Left((BroadcastReceiver(receiver), x)),
+            Err(RecvError::Closed) => Right(BroadcastTerminal::Closed),
+            Err(RecvError::Lagged(n)) => {
+                Right(BroadcastTerminal::Lagged(n, BroadcastReceiver(receiver)))
+            }
+        }
+    }
+}
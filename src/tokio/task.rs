@@ -0,0 +1,61 @@
+use crate::AsyncFiniteMoveIterator;
+use ::tokio::task::{JoinError, JoinSet};
+
+/// Produce the result of each task in `set` as it completes, ending once the set is empty.
+///
+/// A task which panicked or was cancelled produces an `Err` item rather than ending iteration.
+///
+/// # Example
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use moveiter::AsyncFiniteMoveIteratorExt;
+///
+/// let mut set = tokio::task::JoinSet::new();
+/// set.spawn(async { 1 });
+/// set.spawn(async { panic!("boom") });
+/// set.spawn(async { 3 });
+///
+/// let results: Vec<_> = moveiter::tokio::join_set(set).collect().await;
+///
+/// let (mut ok, mut panics) = (vec![], 0);
+/// for r in results {
+///     match r {
+///         Ok(x) => ok.push(x),
+///         Err(e) => panics += e.is_panic() as usize,
+///     }
+/// }
+/// ok.sort();
+/// assert_eq!(ok, vec![1, 3]);
+/// assert_eq!(panics, 1);
+/// # });
+/// ```
+pub fn join_set<T>(set: JoinSet<T>) -> JoinSetTasks<T>
+where
+    T: Send + 'static,
+{
+    JoinSetTasks(set)
+}
+
+/// An [AsyncFiniteMoveIterator] impl from [join_set].
+pub struct JoinSetTasks<T>(JoinSet<T>);
+
+impl<T> JoinSetTasks<T> {
+    /// Stop iterating and recover the set, including any tasks still running.
+    pub fn into_inner(self) -> JoinSet<T> {
+        self.0
+    }
+}
+
+impl<T> AsyncFiniteMoveIterator for JoinSetTasks<T>
+where
+    T: Send + 'static,
+{
+    type Item = Result<T, JoinError>;
+
+    async fn into_next(self) -> Option<(Self, Self::Item)> {
+        let JoinSetTasks(mut set) = self;
+        let x = set.join_next().await?;
+        Some((JoinSetTasks(set), x))
+    }
+}